@@ -0,0 +1,108 @@
+//! Derive macros for the [`eeprom25aa02e48`] crate.
+//!
+//! This crate is re-exported by [`eeprom25aa02e48`] with the `derive`
+//! feature, and should not be used directly.
+//!
+//! [`eeprom25aa02e48`]: https://docs.rs/eeprom25aa02e48
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, LitInt};
+
+/// Derive `EepromRecord` for a struct.
+///
+/// The address of the record is set with `#[eeprom(address = ...)]`, and
+/// every field must implement `FieldValue`.
+#[proc_macro_derive(EepromRecord, attributes(eeprom))]
+pub fn derive_eeprom_record(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let mut address: Option<LitInt> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("eeprom")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("address") {
+                address = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported eeprom attribute"))
+            }
+        })?;
+    }
+    let address: LitInt = address.ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            "missing `#[eeprom(address = ...)]` attribute",
+        )
+    })?;
+
+    let fields: &Fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "EepromRecord can only be derived for structs",
+            ))
+        }
+    };
+
+    let types: Vec<&syn::Type> = fields.iter().map(|f| &f.ty).collect();
+    let members: Vec<syn::Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(n, f)| match &f.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index {
+                index: n as u32,
+                span: f.span(),
+            }),
+        })
+        .collect();
+    let locals: Vec<syn::Ident> = (0..members.len())
+        .map(|n| quote::format_ident!("__field{}", n))
+        .collect();
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let krate = quote!(::eeprom25aa02e48);
+
+    Ok(quote! {
+        impl #impl_generics #krate::EepromRecord for #name #ty_generics #where_clause {
+            const ADDRESS: u8 = #address;
+            const SIZE: usize = 0 #(+ <#types as #krate::FieldValue>::SIZE)*;
+
+            fn write_bytes(&self, buf: &mut [u8]) {
+                let mut offset: usize = 0;
+                #(
+                    let size: usize = <#types as #krate::FieldValue>::SIZE;
+                    buf[offset..offset + size].copy_from_slice(
+                        ::core::convert::AsRef::<[u8]>::as_ref(
+                            &#krate::FieldValue::to_bytes(&self.#members),
+                        ),
+                    );
+                    offset += size;
+                )*
+                let _ = offset;
+            }
+
+            fn read_bytes(buf: &[u8]) -> Self {
+                let mut offset: usize = 0;
+                #(
+                    let size: usize = <#types as #krate::FieldValue>::SIZE;
+                    let mut bytes = <<#types as #krate::FieldValue>::Bytes as ::core::default::Default>::default();
+                    ::core::convert::AsMut::<[u8]>::as_mut(&mut bytes)
+                        .copy_from_slice(&buf[offset..offset + size]);
+                    let #locals = <#types as #krate::FieldValue>::from_bytes(bytes);
+                    offset += size;
+                )*
+                let _ = offset;
+                Self { #(#members: #locals),* }
+            }
+        }
+    })
+}
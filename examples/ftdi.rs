@@ -19,7 +19,7 @@
 //! [adafruit FT232H breakout]: https://www.adafruit.com/product/2264
 //! [libftd2xx crate]: https://github.com/newAM/libftd2xx-rs/
 
-use eeprom25aa02e48::Eeprom25aa02e48;
+use eeprom25aa02e48::{Eeprom25aa02e48, Eui48};
 use embedded_hal::spi::Polarity;
 use ftdi_embedded_hal::{
     libftd2xx::{self, Ft232h},
@@ -34,10 +34,7 @@ fn main() {
     spi.set_clock_polarity(Polarity::IdleLow);
 
     let mut eeprom = Eeprom25aa02e48::new(&spi);
-    let mac: [u8; 6] = eeprom.read_eui48().unwrap();
+    let mac: Eui48 = eeprom.read_eui48().unwrap();
 
-    println!(
-        "MAC address: {:02X?}:{:02X?}:{:02X?}:{:02X?}:{:02X?}:{:02X?}",
-        mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]
-    );
+    println!("MAC address: {mac}");
 }
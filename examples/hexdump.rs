@@ -49,10 +49,7 @@ fn main() {
 
     let mut eeprom = Eeprom25aa02e48::new(&spi);
 
-    let mut all_data: [u8; 256] = [0; 256];
-    eeprom
-        .read(0x00, &mut all_data)
-        .expect("Failed to read data");
+    let all_data: [u8; 256] = eeprom.read_all().expect("Failed to read data");
 
     hexdump(&all_data);
 }
@@ -0,0 +1,180 @@
+use crate::{ARRAY_SIZE, PAGE_SIZE};
+
+/// EEPROM byte address.
+///
+/// Every `u8` is a valid address, the constructors from wider integers
+/// validate that the address is within the array.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::Address;
+///
+/// let address: Address = Address::new(0x4C);
+/// assert_eq!(address.page().get(), 4);
+/// assert_eq!(address.offset_in_page(), 12);
+/// assert_eq!(address.page_start(), Address::new(0x40));
+///
+/// assert_eq!(address.checked_add(0x10), Some(Address::new(0x5C)));
+/// assert_eq!(address.checked_add(0xB4), None);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Address(u8);
+
+impl Address {
+    /// First address of the array.
+    pub const MIN: Address = Address(0x00);
+    /// Last address of the array.
+    pub const MAX: Address = Address(0xFF);
+
+    /// Creates a new address.
+    #[inline]
+    pub const fn new(address: u8) -> Self {
+        Address(address)
+    }
+
+    /// Creates a new address, returning `None` if it is outside of the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Address;
+    ///
+    /// assert_eq!(Address::checked_new(0xFF), Some(Address::MAX));
+    /// assert_eq!(Address::checked_new(0x100), None);
+    /// ```
+    #[inline]
+    pub const fn checked_new(address: usize) -> Option<Self> {
+        if address < ARRAY_SIZE {
+            Some(Address(address as u8))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the address as a `u8`.
+    #[inline]
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the index of the page containing the address.
+    #[inline]
+    pub const fn page(self) -> PageIndex {
+        PageIndex(self.0 / PAGE_SIZE)
+    }
+
+    /// Returns the offset of the address from the start of its page.
+    #[inline]
+    pub const fn offset_in_page(self) -> u8 {
+        self.0 % PAGE_SIZE
+    }
+
+    /// Returns the address of the start of the page containing the address.
+    #[inline]
+    pub const fn page_start(self) -> Self {
+        Address(self.0 - self.offset_in_page())
+    }
+
+    /// Returns the number of bytes from the address to the end of its page.
+    #[inline]
+    pub const fn remaining_in_page(self) -> u8 {
+        PAGE_SIZE - self.offset_in_page()
+    }
+
+    /// Adds an offset to the address, returning `None` if the result is
+    /// outside of the array.
+    #[inline]
+    pub const fn checked_add(self, offset: usize) -> Option<Self> {
+        match (self.0 as usize).checked_add(offset) {
+            Some(address) => Self::checked_new(address),
+            None => None,
+        }
+    }
+}
+
+impl From<u8> for Address {
+    #[inline]
+    fn from(address: u8) -> Self {
+        Address(address)
+    }
+}
+
+impl From<Address> for u8 {
+    #[inline]
+    fn from(address: Address) -> Self {
+        address.0
+    }
+}
+
+impl From<Address> for usize {
+    #[inline]
+    fn from(address: Address) -> Self {
+        address.0.into()
+    }
+}
+
+/// Index of a write page in the array.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::{Address, PageIndex};
+///
+/// let page: PageIndex = PageIndex::new(4).unwrap();
+/// assert_eq!(page.address(), Address::new(0x40));
+/// assert_eq!(PageIndex::new(16), None);
+/// assert_eq!(PageIndex::iter().count(), PageIndex::COUNT);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PageIndex(u8);
+
+impl PageIndex {
+    /// Number of pages in the array.
+    pub const COUNT: usize = ARRAY_SIZE / PAGE_SIZE as usize;
+
+    /// Creates a new page index, returning `None` if it is outside of the
+    /// array.
+    #[inline]
+    pub const fn new(index: u8) -> Option<Self> {
+        if (index as usize) < Self::COUNT {
+            Some(PageIndex(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index as a `u8`.
+    #[inline]
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the address of the start of the page.
+    #[inline]
+    pub const fn address(self) -> Address {
+        Address(self.0 * PAGE_SIZE)
+    }
+
+    /// Returns an iterator over every page in the array.
+    #[inline]
+    pub fn iter() -> impl DoubleEndedIterator<Item = PageIndex> + ExactSizeIterator {
+        (0..Self::COUNT as u8).map(PageIndex)
+    }
+}
+
+impl From<PageIndex> for u8 {
+    #[inline]
+    fn from(index: PageIndex) -> Self {
+        index.0
+    }
+}
+
+impl From<PageIndex> for usize {
+    #[inline]
+    fn from(index: PageIndex) -> Self {
+        index.0.into()
+    }
+}
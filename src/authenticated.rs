@@ -0,0 +1,176 @@
+use crate::{Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE};
+use embedded_hal::delay::DelayNs;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Size of the HMAC-SHA256 tag in bytes.
+const TAG_LEN: usize = 32;
+
+/// Size of the length prefix and tag in bytes.
+const OVERHEAD: usize = 1 + TAG_LEN;
+
+/// Returns an HMAC-SHA256 over the address and the length prefix, to be
+/// updated with the data.
+fn mac(key: &[u8], address: u8, prefix: &[u8; 1]) -> Hmac<Sha256> {
+    let mut mac: Hmac<Sha256> = Hmac::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(&[address]);
+    mac.update(prefix);
+    mac
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Write data followed by an HMAC-SHA256 tag.
+    ///
+    /// The data is stored as a single byte length prefix, the data, and the
+    /// tag, which is 33 bytes more than the data, and may span multiple
+    /// pages.
+    /// The tag covers the address, so data copied to another address fails
+    /// authentication.
+    ///
+    /// The data is not encrypted, see `write_encrypted`, behind the
+    /// `chacha20poly1305` feature, for secrets.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if the data and tag do not fit in the
+    /// array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "hmac")] {
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # fn read(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+    /// #     hal::spi::Transaction::read_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # fn write(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # let blob: Vec<u8> = vec![
+    /// #   0x02, 0x01, 0x00, 0x55, 0xBA, 0x0A, 0xBF, 0xED, 0x01, 0xB9, 0x28, 0xD0,
+    /// #   0x2C, 0x39, 0x6E, 0xA5, 0x98, 0x96, 0xEA, 0xC7, 0xD4, 0xAB, 0xE9, 0xEC,
+    /// #   0xA9, 0xD8, 0x13, 0x64, 0xE2, 0xE2, 0xF4, 0x46, 0xFA, 0xD0, 0x1E,
+    /// # ];
+    /// # fn read_split(address: u8, data: Vec<u8>, tag: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+    /// #     hal::spi::Transaction::read_vec(data),
+    /// #     hal::spi::Transaction::read_vec(tag),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # let mut expectations = vec![];
+    /// # expectations.extend(write(0x80, blob[..16].to_vec()));
+    /// # expectations.extend(write(0x90, blob[16..32].to_vec()));
+    /// # expectations.extend(write(0xA0, blob[32..].to_vec()));
+    /// # expectations.extend(read(0x80, vec![0x02]));
+    /// # expectations.extend(read_split(0x81, blob[1..3].to_vec(), blob[3..].to_vec()));
+    /// # expectations.extend(read(0x80, vec![0x02]));
+    /// # expectations.extend(read_split(0x81, vec![0x00, 0x00], blob[3..].to_vec()));
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// const KEY: &[u8] = b"secret";
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_authenticated(0x80, KEY, &[0x01, 0x00])?;
+    ///
+    /// let mut buf: [u8; 2] = [0; 2];
+    /// assert_eq!(eeprom.read_authenticated(0x80, KEY, &mut buf)?, 2);
+    /// assert_eq!(buf, [0x01, 0x00]);
+    ///
+    /// // debug mode enabled by editing the EEPROM
+    /// assert_eq!(
+    ///     eeprom.read_authenticated(0x80, KEY, &mut buf),
+    ///     Err(Error::AuthenticationFailed)
+    /// );
+    /// assert_eq!(buf, [0x00, 0x00]);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # }
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn write_authenticated(
+        &mut self,
+        address: u8,
+        key: &[u8],
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        let len: usize = data.len();
+        if len + OVERHEAD > ARRAY_SIZE {
+            return Err(Error::DataTooLong);
+        }
+        // checked against ARRAY_SIZE
+        let prefix: [u8; 1] = [len as u8];
+        let mut mac: Hmac<Sha256> = mac(key, address, &prefix);
+        mac.update(data);
+        let tag = mac.finalize().into_bytes();
+        self.write_parts(address, &[&prefix, data, &tag])
+    }
+
+    /// Read data written with [`Eeprom25aa02::write_authenticated`].
+    ///
+    /// Returns the length of the data copied into `buf`.
+    /// The data is read once, into `buf`, and the bytes returned are the
+    /// bytes that were authenticated.
+    /// The data in `buf` is cleared if authentication fails.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Corrupted`] if the length prefix extends past the end of
+    ///   the array.
+    /// * [`Error::DataTooLong`] if `buf` is shorter than the data.
+    /// * [`Error::AuthenticationFailed`] if the key is incorrect, or the data
+    ///   was modified.
+    pub fn read_authenticated(
+        &mut self,
+        address: u8,
+        key: &[u8],
+        buf: &mut [u8],
+    ) -> Result<usize, Error<SPI::Error>> {
+        let mut prefix: [u8; 1] = [0];
+        self.read(address, &mut prefix)?;
+        let len: usize = prefix[0].into();
+        if usize::from(address) + len + OVERHEAD > ARRAY_SIZE {
+            return Err(Error::Corrupted);
+        }
+        if buf.len() < len {
+            return Err(Error::DataTooLong);
+        }
+        let data: &mut [u8] = &mut buf[..len];
+        let mut tag: [u8; TAG_LEN] = [0; TAG_LEN];
+        self.read_split(address + 1, data, &mut tag)?;
+        let mut mac: Hmac<Sha256> = mac(key, address, &prefix);
+        mac.update(data);
+        if mac.verify_slice(&tag).is_err() {
+            data.fill(0);
+            return Err(Error::AuthenticationFailed);
+        }
+        Ok(len)
+    }
+}
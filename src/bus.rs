@@ -0,0 +1,266 @@
+#[cfg(feature = "bus-health")]
+use crate::BusHealth;
+use crate::{
+    commands, Error, NoDelay, StatusRegister, Transport, HALF_DUPLEX_TURNAROUND_NS,
+    WRITE_CYCLE_TIME_MS,
+};
+use core::num::NonZeroU8;
+use embedded_hal::{delay::DelayNs, spi::Operation};
+
+/// Interval between STATUS register polls when the driver owns a delay.
+const WIP_POLL_INTERVAL_US: u32 = 50;
+
+/// Time to wait for a write cycle before giving up, twice the maximum write
+/// cycle time.
+const WIP_TIMEOUT_US: u32 = 2 * WRITE_CYCLE_TIME_MS * 1000;
+
+/// Number of STATUS register polls before giving up when the driver does not
+/// own a delay.
+///
+/// A poll clocks at least 16 bits, which takes 1.6 µs at the maximum clock
+/// frequency of 10 MHz, so this is at least 16 ms.
+pub(crate) const WIP_POLL_LIMIT: u32 = 10_000;
+
+/// SPI bus state shared by the drivers.
+///
+/// This owns the transport and handles everything that does not depend on
+/// the geometry of the part: half-duplex reads, the write enable latch,
+/// STATUS register polling, automatic recovery and bus health.
+#[derive(Debug, Default)]
+pub(crate) struct Bus<SPI, D> {
+    spi: SPI,
+    delay: Option<D>,
+    half_duplex: bool,
+    /// Consecutive failed transactions, counted by [`BusHealth`] when the
+    /// `bus-health` feature is enabled.
+    #[cfg(not(feature = "bus-health"))]
+    consecutive_errors: u8,
+    recovery_threshold: Option<NonZeroU8>,
+    #[cfg(feature = "bus-health")]
+    health: BusHealth,
+}
+
+impl<SPI, D> Bus<SPI, D> {
+    #[inline]
+    pub(crate) fn new(spi: SPI, delay: Option<D>, half_duplex: bool) -> Self {
+        Bus {
+            spi,
+            delay,
+            half_duplex,
+            #[cfg(not(feature = "bus-health"))]
+            consecutive_errors: 0,
+            recovery_threshold: None,
+            #[cfg(feature = "bus-health")]
+            health: BusHealth::default(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn free(self) -> SPI {
+        self.spi
+    }
+
+    #[inline]
+    pub(crate) fn half_duplex(&self) -> bool {
+        self.half_duplex
+    }
+
+    #[inline]
+    pub(crate) fn has_delay(&self) -> bool {
+        self.delay.is_some()
+    }
+
+    #[cfg(feature = "bus-health")]
+    #[inline]
+    pub(crate) fn health(&self) -> &BusHealth {
+        &self.health
+    }
+
+    #[cfg(feature = "bus-health")]
+    #[inline]
+    pub(crate) fn reset_health(&mut self) {
+        self.health = BusHealth::default();
+    }
+
+    #[inline]
+    pub(crate) fn set_recovery_threshold(&mut self, threshold: Option<NonZeroU8>) {
+        self.recovery_threshold = threshold;
+    }
+
+    /// Number of SPI transactions that have failed since the last successful
+    /// transaction.
+    #[inline(always)]
+    fn consecutive_errors(&self) -> u32 {
+        #[cfg(feature = "bus-health")]
+        {
+            self.health.consecutive_errors()
+        }
+        #[cfg(not(feature = "bus-health"))]
+        {
+            self.consecutive_errors.into()
+        }
+    }
+}
+
+impl<SPI, D> Bus<SPI, D>
+where
+    SPI: Transport,
+    D: DelayNs,
+{
+    /// Run a SPI transaction, recording the result for the automatic recovery
+    /// policy and bus health statistics.
+    #[inline(always)]
+    pub(crate) fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), SPI::Error> {
+        let result = self.spi.transaction(operations);
+        #[cfg(feature = "bus-health")]
+        self.health.record(&result);
+        #[cfg(not(feature = "bus-health"))]
+        if result.is_err() {
+            self.consecutive_errors = self.consecutive_errors.saturating_add(1);
+        } else {
+            self.consecutive_errors = 0;
+        }
+        result
+    }
+
+    /// Context manager to ensure the write latch is always disabled after an operation.
+    #[inline(always)]
+    pub(crate) fn with_write_latch(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), SPI::Error> {
+        self.transaction(&mut [Operation::Write(&commands::encode_wren())])?;
+        let result = self.transaction(operations);
+        // write latch automatically resets on successful write
+        if result.is_err() {
+            self.transaction(&mut [Operation::Write(&commands::encode_wrdi())])?;
+        }
+        result
+    }
+
+    /// Send a read command, then read the response into `head` followed by
+    /// `tail` in a single transaction.
+    ///
+    /// In half-duplex mode the bus turnaround delay is inserted before the
+    /// response.
+    pub(crate) fn read(
+        &mut self,
+        cmd: &[u8],
+        head: &mut [u8],
+        tail: &mut [u8],
+    ) -> Result<(), SPI::Error> {
+        match (self.half_duplex, tail.is_empty()) {
+            (true, true) => self.transaction(&mut [
+                Operation::Write(cmd),
+                Operation::DelayNs(HALF_DUPLEX_TURNAROUND_NS),
+                Operation::Read(head),
+            ]),
+            (true, false) => self.transaction(&mut [
+                Operation::Write(cmd),
+                Operation::DelayNs(HALF_DUPLEX_TURNAROUND_NS),
+                Operation::Read(head),
+                Operation::Read(tail),
+            ]),
+            (false, true) => self.transaction(&mut [Operation::Write(cmd), Operation::Read(head)]),
+            (false, false) => self.transaction(&mut [
+                Operation::Write(cmd),
+                Operation::Read(head),
+                Operation::Read(tail),
+            ]),
+        }
+    }
+
+    /// Apply the automatic recovery policy before an operation.
+    #[inline(always)]
+    pub(crate) fn begin(&mut self) -> Result<(), Error<SPI::Error>> {
+        match self.recovery_threshold {
+            Some(threshold) if self.consecutive_errors() >= u32::from(threshold.get()) => {
+                match self.recover() {
+                    Ok(()) => Err(Error::Recovered),
+                    Err(_) => Err(Error::Unrecoverable),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that a device is present by disabling the write enable latch
+    /// and reading it back.
+    pub(crate) fn probe(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.transaction(&mut [Operation::Write(&commands::encode_wrdi())])?;
+        if self.read_status()?.wel() {
+            Err(Error::NoDevice)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Recover the device after a failure.
+    #[inline]
+    pub(crate) fn recover(&mut self) -> Result<(), Error<SPI::Error>> {
+        // the successful transactions of the probe clear the count
+        self.probe()
+    }
+
+    pub(crate) fn read_status(&mut self) -> Result<StatusRegister, Error<SPI::Error>> {
+        let mut status: [u8; 1] = [0];
+        self.read(&commands::encode_rdsr(), &mut status, &mut [])?;
+        Ok(StatusRegister::from_bits(status[0]))
+    }
+
+    #[inline]
+    pub(crate) fn is_busy(&mut self) -> Result<bool, Error<SPI::Error>> {
+        Ok(self.read_status()?.wip())
+    }
+
+    /// Wait for the write cycle to complete, pacing the polls with the owned
+    /// delay if there is one.
+    pub(crate) fn wait_write_complete(&mut self) -> Result<(), Error<SPI::Error>> {
+        match self.delay.take() {
+            Some(mut delay) => {
+                let result: Result<(), Error<SPI::Error>> =
+                    self.wait_write_complete_with(&mut delay);
+                self.delay = Some(delay);
+                result
+            }
+            None => self.poll_write_complete(WIP_POLL_LIMIT, &mut NoDelay),
+        }
+    }
+
+    /// Poll the STATUS register until the write cycle completes, waiting
+    /// with `delay` between polls.
+    pub(crate) fn wait_write_complete_with(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<SPI::Error>> {
+        self.poll_write_complete(WIP_TIMEOUT_US / WIP_POLL_INTERVAL_US, delay)
+    }
+
+    /// Poll the STATUS register up to `polls` times until the write cycle
+    /// completes.
+    fn poll_write_complete(
+        &mut self,
+        polls: u32,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<SPI::Error>> {
+        for _ in 0..polls {
+            if !self.is_busy()? {
+                return Ok(());
+            }
+            delay.delay_us(WIP_POLL_INTERVAL_US);
+        }
+        Err(Error::Timeout)
+    }
+
+    /// Wait for the write cycle to complete if the driver owns a delay.
+    #[inline(always)]
+    pub(crate) fn auto_wait(&mut self) -> Result<(), Error<SPI::Error>> {
+        if self.delay.is_some() {
+            self.wait_write_complete()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Eeprom25aa02, Error, NoDelay, Transport, Variant, ARRAY_SIZE, E48, PAGE_SIZE};
+use embedded_hal::delay::DelayNs;
+
+/// EEPROM driver with a read cache.
+///
+/// Pages are read into the cache the first time they are read, later reads
+/// of the same page do not access the bus.
+/// Writes go to the EEPROM and update the cache.
+///
+/// The cache does not see changes made without this driver, such as by
+/// another bus master, call [`invalidate`](Self::invalidate) to discard it.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # fn read_page(address: u8) -> Vec<hal::spi::Transaction<u8>> {
+/// #   let mut data: Vec<u8> = vec![0x00; 16];
+/// #   data[2] = 0x01;
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+/// #     hal::spi::Transaction::read_vec(data),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # let mut expectations = read_page(0x10);
+/// # expectations.extend(read_page(0x10));
+/// # let spi = hal::spi::Mock::new(&expectations);
+/// use eeprom25aa02e48::{CachedEeprom, Eeprom25aa02e48};
+///
+/// let mut eeprom = CachedEeprom::new(Eeprom25aa02e48::new(spi));
+///
+/// // the first read fills the cache
+/// assert_eq!(eeprom.read_byte(0x12)?, 0x01);
+/// assert_eq!(eeprom.read_byte(0x12)?, 0x01);
+///
+/// // read the EEPROM again
+/// eeprom.invalidate();
+/// assert_eq!(eeprom.read_byte(0x12)?, 0x01);
+/// # let mut spi = eeprom.into_inner().free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct CachedEeprom<SPI, V = E48, D = NoDelay> {
+    eeprom: Eeprom25aa02<SPI, V, D>,
+    cache: [u8; ARRAY_SIZE],
+    /// Bit `n` is set if page `n` is cached.
+    valid: u16,
+}
+
+impl<SPI, V, D> CachedEeprom<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Creates a new cached driver with an empty cache.
+    #[inline]
+    pub fn new(eeprom: Eeprom25aa02<SPI, V, D>) -> Self {
+        CachedEeprom {
+            eeprom,
+            cache: [0; ARRAY_SIZE],
+            valid: 0,
+        }
+    }
+
+    /// Free the underlying driver.
+    #[inline]
+    pub fn into_inner(self) -> Eeprom25aa02<SPI, V, D> {
+        self.eeprom
+    }
+
+    /// Discard every cached page.
+    #[inline]
+    pub fn invalidate(&mut self) {
+        self.valid = 0;
+    }
+
+    /// Read from the cache, reading pages that are not cached from the
+    /// EEPROM.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the read extends past the end of
+    /// the array.
+    pub fn read(&mut self, address: u8, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        let start: usize = address.into();
+        let end: usize = start + buf.len();
+        if end > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        let page_size: usize = PAGE_SIZE.into();
+        for page in start / page_size..end.div_ceil(page_size) {
+            if self.valid & (1 << page) == 0 {
+                let page_start: usize = page * page_size;
+                self.eeprom.read(
+                    page_start as u8,
+                    &mut self.cache[page_start..page_start + page_size],
+                )?;
+                self.valid |= 1 << page;
+            }
+        }
+        buf.copy_from_slice(&self.cache[start..end]);
+        Ok(())
+    }
+
+    /// Read a byte from the cache.
+    ///
+    /// See [`CachedEeprom::read`].
+    pub fn read_byte(&mut self, address: u8) -> Result<u8, Error<SPI::Error>> {
+        let mut buf: [u8; 1] = [0];
+        self.read(address, &mut buf)?;
+        Ok(buf[0])
+    }
+
+    /// Write to the EEPROM, updating the cache.
+    ///
+    /// See [`Eeprom25aa02::write`].
+    pub fn write(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        let start: usize = address.into();
+        let page_size: usize = PAGE_SIZE.into();
+        let result: Result<(), Error<SPI::Error>> = self.eeprom.write(address, data);
+        match result {
+            Ok(()) => {
+                self.cache[start..start + data.len()].copy_from_slice(data);
+            }
+            Err(_) => {
+                // the pages may be partially written
+                let end: usize = (start + data.len()).min(ARRAY_SIZE);
+                for page in start / page_size..end.div_ceil(page_size) {
+                    self.valid &= !(1 << page);
+                }
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,135 @@
+use crate::Error;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Serialization format used by the config storage methods.
+///
+/// Implementations are provided for [postcard] with the `postcard` feature,
+/// and CBOR with the `cbor` feature, which requires an allocator.
+///
+/// # Example
+///
+/// A codec for a custom wire format.
+///
+/// ```
+/// use eeprom25aa02e48::{Codec, CodecError};
+/// use serde::{de::DeserializeOwned, Serialize};
+///
+/// /// Postcard with a trailing `0x00` byte.
+/// struct Terminated;
+///
+/// impl Codec for Terminated {
+///     fn encode<T: Serialize + ?Sized>(value: &T, buf: &mut [u8]) -> Result<usize, CodecError> {
+///         let (last, buf) = buf.split_last_mut().ok_or(CodecError::BufferFull)?;
+///         let len: usize = postcard::to_slice(value, buf)
+///             .map_err(|_| CodecError::BufferFull)?
+///             .len();
+///         *last = 0x00;
+///         Ok(len + 1)
+///     }
+///
+///     fn decode<T: DeserializeOwned>(buf: &mut [u8]) -> Result<T, CodecError> {
+///         match buf.split_last() {
+///             Some((0x00, buf)) => postcard::from_bytes(buf).map_err(|_| CodecError::Decode),
+///             _ => Err(CodecError::Decode),
+///         }
+///     }
+/// }
+/// ```
+pub trait Codec {
+    /// Serialize a value into `buf`, returning the number of bytes written.
+    fn encode<T: Serialize + ?Sized>(value: &T, buf: &mut [u8]) -> Result<usize, CodecError>;
+
+    /// Deserialize a value from `buf`.
+    ///
+    /// The buffer contains exactly the bytes written by
+    /// [`encode`](Self::encode), and may be modified during decoding.
+    fn decode<T: DeserializeOwned>(buf: &mut [u8]) -> Result<T, CodecError>;
+}
+
+/// [`Codec`] error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CodecError {
+    /// The serialized value does not fit in the buffer.
+    BufferFull,
+    /// The value could not be serialized.
+    Encode,
+    /// The buffer does not contain a valid value.
+    Decode,
+}
+
+impl CodecError {
+    pub(crate) fn into_error<E>(self) -> Error<E> {
+        match self {
+            CodecError::BufferFull => Error::DataTooLong,
+            CodecError::Encode => Error::Serialization,
+            CodecError::Decode => Error::Corrupted,
+        }
+    }
+}
+
+impl core::fmt::Display for CodecError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CodecError::BufferFull => f.write_str("buffer full"),
+            CodecError::Encode => f.write_str("encoding failed"),
+            CodecError::Decode => f.write_str("decoding failed"),
+        }
+    }
+}
+
+impl core::error::Error for CodecError {}
+
+/// [postcard] [`Codec`].
+#[cfg(feature = "postcard")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Postcard;
+
+#[cfg(feature = "postcard")]
+impl Codec for Postcard {
+    fn encode<T: Serialize + ?Sized>(value: &T, buf: &mut [u8]) -> Result<usize, CodecError> {
+        match postcard::to_slice(value, buf) {
+            Ok(data) => Ok(data.len()),
+            Err(postcard::Error::SerializeBufferFull) => Err(CodecError::BufferFull),
+            Err(_) => Err(CodecError::Encode),
+        }
+    }
+
+    fn decode<T: DeserializeOwned>(buf: &mut [u8]) -> Result<T, CodecError> {
+        postcard::from_bytes(buf).map_err(|_| CodecError::Decode)
+    }
+}
+
+/// [CBOR] [`Codec`], using [ciborium].
+///
+/// ciborium requires an allocator, the `cbor` feature can only be used on
+/// targets with a global allocator.
+///
+/// [CBOR]: https://cbor.io
+#[cfg(feature = "cbor")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cbor;
+
+/// Size of the buffer used to decode CBOR strings.
+///
+/// Longer strings are decoded in chunks of this size.
+#[cfg(feature = "cbor")]
+const CBOR_SCRATCH_SIZE: usize = 16;
+
+#[cfg(feature = "cbor")]
+impl Codec for Cbor {
+    fn encode<T: Serialize + ?Sized>(value: &T, buf: &mut [u8]) -> Result<usize, CodecError> {
+        let capacity: usize = buf.len();
+        let mut writer: &mut [u8] = buf;
+        match ciborium::into_writer(value, &mut writer) {
+            Ok(()) => Ok(capacity - writer.len()),
+            Err(ciborium::ser::Error::Io(_)) => Err(CodecError::BufferFull),
+            Err(_) => Err(CodecError::Encode),
+        }
+    }
+
+    fn decode<T: DeserializeOwned>(buf: &mut [u8]) -> Result<T, CodecError> {
+        let mut scratch: [u8; CBOR_SCRATCH_SIZE] = [0; CBOR_SCRATCH_SIZE];
+        ciborium::de::from_reader_with_buffer(&*buf, &mut scratch).map_err(|_| CodecError::Decode)
+    }
+}
@@ -0,0 +1,55 @@
+//! Command encoding.
+//!
+//! These produce the bytes clocked out before the data phase of each
+//! instruction, for use with DMA descriptors or custom transports.
+//!
+//! # Example
+//!
+//! ```
+//! use eeprom25aa02e48::{commands, instruction};
+//!
+//! const READ_EUI48: [u8; 2] = commands::encode_read(0xFA);
+//! assert_eq!(READ_EUI48, [instruction::READ, 0xFA]);
+//! ```
+
+use crate::instruction;
+
+/// Encode a READ instruction, followed by the data to read.
+#[inline]
+pub const fn encode_read(address: u8) -> [u8; 2] {
+    [instruction::READ, address]
+}
+
+/// Encode a WRITE instruction, followed by up to a page of data.
+///
+/// The write enable latch must be set first, see [`encode_wren`].
+#[inline]
+pub const fn encode_write(address: u8) -> [u8; 2] {
+    [instruction::WRITE, address]
+}
+
+/// Encode a WREN instruction.
+#[inline]
+pub const fn encode_wren() -> [u8; 1] {
+    [instruction::WREN]
+}
+
+/// Encode a WRDI instruction.
+#[inline]
+pub const fn encode_wrdi() -> [u8; 1] {
+    [instruction::WRDI]
+}
+
+/// Encode a RDSR instruction, followed by the STATUS register value.
+#[inline]
+pub const fn encode_rdsr() -> [u8; 1] {
+    [instruction::RDSR]
+}
+
+/// Encode a WRSR instruction with the new STATUS register value.
+///
+/// The write enable latch must be set first, see [`encode_wren`].
+#[inline]
+pub const fn encode_wrsr(status: u8) -> [u8; 2] {
+    [instruction::WRSR, status]
+}
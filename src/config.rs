@@ -0,0 +1,218 @@
+use crate::{Codec, CodecError, Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE};
+use core::marker::PhantomData;
+use embedded_hal::delay::DelayNs;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Size of the version and length header in bytes.
+const HEADER_SIZE: usize = 2;
+
+/// Config stored at a fixed EEPROM address with a schema version.
+///
+/// The config is stored as a version byte, a length byte, and the payload
+/// serialized with the codec `C`.
+/// Configs written by older firmware are converted with a migration closure
+/// when loaded, see [`Eeprom25aa02::load_config`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "postcard")] {
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x40]),
+/// #   hal::spi::Transaction::read_vec(vec![0x01, 0x02]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x42]),
+/// #   hal::spi::Transaction::read_vec(vec![0xE8, 0x07]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x40]),
+/// #   hal::spi::Transaction::write_vec(vec![0x02, 0x03, 0xE8, 0x07, 0x03]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{CodecError, Eeprom25aa02e48, Postcard, VersionedConfig};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize)]
+/// struct SettingsV1 {
+///     timeout_ms: u16,
+/// }
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Settings {
+///     timeout_ms: u16,
+///     retries: u8,
+/// }
+///
+/// const SETTINGS: VersionedConfig<Settings, Postcard> = VersionedConfig::new(0x40, 2);
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// let mut buf: [u8; 8] = [0; 8];
+/// let settings: Settings = eeprom.load_config(&SETTINGS, &mut buf, |old| match old.version() {
+///     1 => {
+///         let v1: SettingsV1 = old.decode()?;
+///         Ok(Settings {
+///             timeout_ms: v1.timeout_ms,
+///             retries: 3,
+///         })
+///     }
+///     _ => Err(CodecError::Decode),
+/// })?;
+/// assert_eq!(settings, Settings { timeout_ms: 1000, retries: 3 });
+///
+/// // persist the migrated config
+/// eeprom.store_config(&SETTINGS, &settings, &mut buf)?;
+/// # let mut spi = eeprom.free(); spi.done();
+/// # }
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VersionedConfig<T, C> {
+    address: u8,
+    version: u8,
+    value: PhantomData<fn() -> (T, C)>,
+}
+
+impl<T, C: Codec> VersionedConfig<T, C> {
+    /// Creates a new config at an EEPROM memory address with the current
+    /// schema version.
+    #[inline]
+    pub const fn new(address: u8, version: u8) -> Self {
+        VersionedConfig {
+            address,
+            version,
+            value: PhantomData,
+        }
+    }
+
+    /// EEPROM memory address of the config.
+    #[inline]
+    pub const fn address(&self) -> u8 {
+        self.address
+    }
+
+    /// Current schema version of the config.
+    #[inline]
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+/// Config stored with an older schema version.
+///
+/// Passed to the migration closure of [`Eeprom25aa02::load_config`].
+pub struct Migration<'a, C> {
+    version: u8,
+    payload: &'a mut [u8],
+    codec: PhantomData<C>,
+}
+
+impl<C: Codec> Migration<'_, C> {
+    /// Schema version of the stored config.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Deserialize the stored config as the type of its schema version.
+    pub fn decode<U: DeserializeOwned>(self) -> Result<U, CodecError> {
+        C::decode(self.payload)
+    }
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Store a config with the current schema version.
+    ///
+    /// `buf` holds the serialized config, and must be at least as long as
+    /// it.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// See [`VersionedConfig`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::DataTooLong`] if the serialized config does not fit in
+    ///   `buf`, or is longer than 255 bytes.
+    /// * [`Error::OutOfBounds`] if the serialized config extends past the
+    ///   end of the array.
+    /// * [`Error::Serialization`] if the config cannot be serialized.
+    pub fn store_config<T: Serialize, C: Codec>(
+        &mut self,
+        config: &VersionedConfig<T, C>,
+        value: &T,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        let len: usize = C::encode(value, buf).map_err(CodecError::into_error)?;
+        let len_byte: u8 = u8::try_from(len).map_err(|_| Error::DataTooLong)?;
+        self.write_parts(config.address, &[&[config.version, len_byte], &buf[..len]])
+    }
+
+    /// Load a config.
+    ///
+    /// If the config was stored with an older schema version `migrate` is
+    /// called to convert it to the current version.
+    /// The migrated config is not written back, use
+    /// [`Eeprom25aa02::store_config`] to persist it.
+    ///
+    /// `buf` holds the serialized config while it is decoded.
+    ///
+    /// See [`VersionedConfig`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Corrupted`] if the length extends past the end of the
+    ///   array, or the config cannot be deserialized.
+    /// * [`Error::DataTooLong`] if `buf` is shorter than the serialized
+    ///   config.
+    /// * [`Error::UnsupportedVersion`] if the config was stored with a newer
+    ///   schema version.
+    pub fn load_config<T, C, F>(
+        &mut self,
+        config: &VersionedConfig<T, C>,
+        buf: &mut [u8],
+        migrate: F,
+    ) -> Result<T, Error<SPI::Error>>
+    where
+        T: DeserializeOwned,
+        C: Codec,
+        F: FnOnce(Migration<'_, C>) -> Result<T, CodecError>,
+    {
+        let mut header: [u8; HEADER_SIZE] = [0; HEADER_SIZE];
+        self.read(config.address, &mut header)?;
+        let [version, len] = header;
+        let len: usize = len.into();
+        if usize::from(config.address) + HEADER_SIZE + len > ARRAY_SIZE {
+            return Err(Error::Corrupted);
+        }
+        let payload: &mut [u8] = buf.get_mut(..len).ok_or(Error::DataTooLong)?;
+        self.read(config.address + HEADER_SIZE as u8, payload)?;
+
+        let value: Result<T, CodecError> = if version == config.version {
+            C::decode(payload)
+        } else if version < config.version {
+            migrate(Migration {
+                version,
+                payload,
+                codec: PhantomData,
+            })
+        } else {
+            return Err(Error::UnsupportedVersion);
+        };
+        value.map_err(CodecError::into_error)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{crc::crc8, Eeprom25aa02, Error, PageIndex, Transport, Variant, PAGE_SIZE};
+use embedded_hal::delay::DelayNs;
+
+/// Size of a counter slot in bytes.
+const SLOT_SIZE: usize = 4;
+
+/// Number of slots in a page.
+const SLOTS: usize = PAGE_SIZE as usize / SLOT_SIZE;
+
+/// Wear-leveled counter occupying one page.
+///
+/// Each increment writes the next of four slots, each holding a 24-bit
+/// value and a CRC-8, so every byte is written once per four increments.
+/// The counter is the largest value in a valid slot.
+/// If power is lost during an increment the torn slot fails its CRC and the
+/// previous value is kept.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let mut page: Vec<u8> = vec![0xFF; 16];
+/// # page[4..8].copy_from_slice(&[41, 0x00, 0x00, 0xBD]);
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x30]),
+/// #   hal::spi::Transaction::read_vec(page),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x38]),
+/// #   hal::spi::Transaction::write_vec(vec![42, 0x00, 0x00, 0x31]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, PageIndex, PersistentCounter};
+///
+/// const BOOT_COUNT: PersistentCounter = PersistentCounter::new(PageIndex::new(3).unwrap());
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// let boots: u32 = eeprom.increment_counter(&BOOT_COUNT)?;
+/// assert_eq!(boots, 42);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PersistentCounter {
+    page: PageIndex,
+}
+
+impl PersistentCounter {
+    /// Maximum value of the counter, increments saturate at this value.
+    pub const MAX: u32 = 0x00FF_FFFF;
+
+    /// Creates a new counter in a page.
+    #[inline]
+    pub const fn new(page: PageIndex) -> Self {
+        PersistentCounter { page }
+    }
+
+    /// Page containing the counter.
+    #[inline]
+    pub const fn page(&self) -> PageIndex {
+        self.page
+    }
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Read the value of a counter.
+    ///
+    /// Returns zero if no slot is valid, such as on a blank page.
+    pub fn read_counter(&mut self, counter: &PersistentCounter) -> Result<u32, Error<SPI::Error>> {
+        let page: [u8; PAGE_SIZE as usize] = self.read_array(counter.page.address().get())?;
+        Ok(page
+            .chunks_exact(SLOT_SIZE)
+            .filter(|slot| crc8(&slot[..3]) == slot[3])
+            .map(|slot| u32::from_le_bytes([slot[0], slot[1], slot[2], 0]))
+            .max()
+            .unwrap_or(0))
+    }
+
+    /// Increment a counter, returning the new value.
+    ///
+    /// Waits for the write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// See [`PersistentCounter`] for an example.
+    pub fn increment_counter(
+        &mut self,
+        counter: &PersistentCounter,
+    ) -> Result<u32, Error<SPI::Error>> {
+        let value: u32 = self.read_counter(counter)?;
+        if value == PersistentCounter::MAX {
+            return Ok(value);
+        }
+        let value: u32 = value + 1;
+        let bytes: [u8; 4] = value.to_le_bytes();
+        let slot: [u8; SLOT_SIZE] = [bytes[0], bytes[1], bytes[2], crc8(&bytes[..3])];
+        let offset: usize = value as usize % SLOTS * SLOT_SIZE;
+        self.write(counter.page.address().get() + offset as u8, &slot)?;
+        Ok(value)
+    }
+}
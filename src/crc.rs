@@ -0,0 +1,166 @@
+use crate::{
+    address_range, page_chunks, Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE, PAGE_SIZE,
+};
+use core::ops::{Range, RangeBounds};
+use embedded_hal::delay::DelayNs;
+
+/// CRC-8/HITAG, polynomial 0x1D, initial value 0xFF.
+///
+/// The initial value is not zero so that data read as all `0x00`, for
+/// example from a missing device with MISO pulled low, fails the check.
+pub(crate) const fn crc8(data: &[u8]) -> u8 {
+    crc8_update(0xFF, data)
+}
+
+/// Update a CRC-8/HITAG with more data, for data read in chunks.
+pub(crate) const fn crc8_update(mut crc: u8, data: &[u8]) -> u8 {
+    let mut i: usize = 0;
+    while i < data.len() {
+        crc ^= data[i];
+        let mut bit: u8 = 0;
+        while bit < 8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x1D
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+/// Update a CRC-16/CCITT-FALSE, polynomial 0x1021, initial value 0xFFFF.
+const fn crc16_update(mut crc: u16, data: &[u8]) -> u16 {
+    let mut i: usize = 0;
+    while i < data.len() {
+        crc ^= (data[i] as u16) << 8;
+        let mut bit: u8 = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+/// Update a CRC-32/ISO-HDLC, reflected polynomial 0xEDB88320, initial value
+/// and final XOR 0xFFFFFFFF.
+const fn crc32_update(mut crc: u32, data: &[u8]) -> u32 {
+    let mut i: usize = 0;
+    while i < data.len() {
+        crc ^= data[i] as u32;
+        let mut bit: u8 = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Read a range of addresses through `update`, one page at a time.
+    fn fold_pages<T>(
+        &mut self,
+        range: impl RangeBounds<u8>,
+        init: T,
+        update: impl Fn(T, &[u8]) -> T,
+    ) -> Result<T, Error<SPI::Error>> {
+        self.fold_range(address_range(range), init, update)
+    }
+
+    /// Read a range of array indices through `update`, one page at a time.
+    pub(crate) fn fold_range<T>(
+        &mut self,
+        range: Range<usize>,
+        init: T,
+        update: impl Fn(T, &[u8]) -> T,
+    ) -> Result<T, Error<SPI::Error>> {
+        if range.end > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        let mut acc: T = init;
+        for chunk in page_chunks(range) {
+            let buf: &mut [u8] = &mut buf[..chunk.len()];
+            self.read(chunk.start as u8, buf)?;
+            acc = update(acc, buf);
+        }
+        Ok(acc)
+    }
+
+    /// Calculate the CRC-16/CCITT-FALSE of a range of addresses.
+    ///
+    /// Data is streamed through a buffer of up to one page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::read_vec(b"123456789".to_vec()),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.crc16(0x00..0x09)?, 0x29B1);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn crc16(&mut self, range: impl RangeBounds<u8>) -> Result<u16, Error<SPI::Error>> {
+        self.fold_pages(range, 0xFFFF, crc16_update)
+    }
+
+    /// Calculate the CRC-32/ISO-HDLC of a range of addresses.
+    ///
+    /// This is the CRC used by Ethernet and zlib.
+    /// Data is streamed through a buffer of up to one page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x0C]),
+    /// #   hal::spi::Transaction::read_vec(b"1234".to_vec()),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(b"56789".to_vec()),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.crc32(0x0C..=0x14)?, 0xCBF4_3926);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn crc32(&mut self, range: impl RangeBounds<u8>) -> Result<u32, Error<SPI::Error>> {
+        self.fold_pages(range, 0xFFFF_FFFF, crc32_update)
+            .map(|crc| !crc)
+    }
+}
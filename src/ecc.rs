@@ -0,0 +1,201 @@
+use crate::{page_chunks, Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE, PAGE_SIZE};
+use embedded_hal::delay::DelayNs;
+
+/// Extended Hamming(8,4) codewords, indexed by nibble.
+///
+/// Bit `n` of a codeword is position `n` of the Hamming(7,4) code, and bit 0
+/// is the parity of the entire codeword.
+const CODEWORDS: [u8; 16] = [
+    0x00, 0x0F, 0x33, 0x3C, 0x55, 0x5A, 0x66, 0x69, 0x96, 0x99, 0xA5, 0xAA, 0xC3, 0xCC, 0xF0, 0xFF,
+];
+
+/// Decode a codeword, returning the nibble and `true` if a bit was
+/// corrected, or `None` if two bits are in error.
+fn decode(mut codeword: u8) -> Option<(u8, bool)> {
+    let syndrome: u8 = (1..8)
+        .filter(|position| codeword & (1 << position) != 0)
+        .fold(0, |syndrome, position| syndrome ^ position);
+    let corrected: bool = codeword.count_ones() % 2 == 1;
+    match (syndrome, corrected) {
+        (0, _) => codeword &= !0x01,
+        (_, true) => codeword ^= 1 << syndrome,
+        (_, false) => return None,
+    }
+    let nibble: u8 = ((codeword >> 3) & 0x01) | ((codeword >> 4) & 0x0E);
+    Some((nibble, corrected))
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Write data protected by an error correcting code.
+    ///
+    /// Every nibble is stored as an extended Hamming(8,4) codeword, so the
+    /// data uses twice its length in the EEPROM, low nibble first.
+    /// [`Eeprom25aa02::read_ecc`] corrects a single bit error in every
+    /// codeword, and detects two bit errors.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if the encoded data is longer than the
+    /// array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # fn read(data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::READ, 0x40]),
+    /// #     hal::spi::Transaction::read_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # let mut expectations = vec![
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x40]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x5A, 0xA5]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ];
+    /// # expectations.extend(read(vec![0x5A, 0xA5]));
+    /// # expectations.extend(read(vec![0x7A, 0xA5]));
+    /// # expectations.extend(read(vec![0x5C, 0xA5]));
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_ecc(0x40, &[0xA5])?;
+    ///
+    /// let mut buf: [u8; 1] = [0];
+    /// assert_eq!(eeprom.read_ecc(0x40, &mut buf)?, 0);
+    /// assert_eq!(buf, [0xA5]);
+    ///
+    /// // one bit flipped
+    /// assert_eq!(eeprom.read_ecc(0x40, &mut buf)?, 1);
+    /// assert_eq!(buf, [0xA5]);
+    ///
+    /// // two bits flipped
+    /// assert_eq!(eeprom.read_ecc(0x40, &mut buf), Err(Error::Corrupted));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn write_ecc(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if data.len() * 2 > ARRAY_SIZE {
+            return Err(Error::DataTooLong);
+        }
+        let codewords = data.iter().flat_map(|&byte| {
+            [
+                CODEWORDS[usize::from(byte & 0x0F)],
+                CODEWORDS[usize::from(byte >> 4)],
+            ]
+        });
+        self.write_iter(address, data.len() * 2, codewords)
+    }
+
+    /// Read data written with [`Eeprom25aa02::write_ecc`], filling `buf`.
+    ///
+    /// Returns the number of corrected bit errors.
+    /// The errors are not corrected in the EEPROM, use
+    /// [`Eeprom25aa02::scrub_ecc`] to correct them.
+    ///
+    /// See [`Eeprom25aa02::write_ecc`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::DataTooLong`] if the encoded data is longer than the array.
+    /// * [`Error::Corrupted`] if a codeword has more than one bit error.
+    pub fn read_ecc(&mut self, address: u8, buf: &mut [u8]) -> Result<usize, Error<SPI::Error>> {
+        if buf.len() * 2 > ARRAY_SIZE {
+            return Err(Error::DataTooLong);
+        }
+        let start: usize = address.into();
+        let end: usize = start + buf.len() * 2;
+        if end > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        let mut corrected: usize = 0;
+        for chunk in page_chunks(start..end) {
+            let mut raw: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+            let raw: &mut [u8] = &mut raw[..chunk.len()];
+            self.read(chunk.start as u8, raw)?;
+            // codewords are low nibble first, and a page may end between
+            // the two codewords of a byte
+            for (index, &codeword) in (chunk.start - start..).zip(raw.iter()) {
+                let (nibble, nibble_corrected) = decode(codeword).ok_or(Error::Corrupted)?;
+                let byte: &mut u8 = &mut buf[index / 2];
+                if index % 2 == 0 {
+                    *byte = nibble;
+                } else {
+                    *byte |= nibble << 4;
+                }
+                corrected += usize::from(nibble_corrected);
+            }
+        }
+        Ok(corrected)
+    }
+
+    /// Correct bit errors in `len` bytes of data written with
+    /// [`Eeprom25aa02::write_ecc`].
+    ///
+    /// The data is read a page at a time, and pages with a corrected bit
+    /// error are rewritten.
+    /// Returns the number of corrected bit errors.
+    ///
+    /// See [`Eeprom25aa02::scrub`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::DataTooLong`] if the encoded data is longer than the array.
+    /// * [`Error::Corrupted`] if a codeword has more than one bit error.
+    ///   The remaining codewords are still corrected, and the uncorrectable
+    ///   codewords are left unchanged.
+    pub fn scrub_ecc(&mut self, address: u8, len: usize) -> Result<usize, Error<SPI::Error>> {
+        let end: usize = usize::from(address) + len * 2;
+        if end > ARRAY_SIZE {
+            return Err(Error::DataTooLong);
+        }
+        let mut corrected: usize = 0;
+        let mut corrupted: bool = false;
+        for start in (usize::from(address)..end).step_by(PAGE_SIZE.into()) {
+            let mut raw: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+            let raw: &mut [u8] = &mut raw[..(end - start).min(PAGE_SIZE.into())];
+            // less than the end of the array
+            let start: u8 = start as u8;
+            self.read(start, raw)?;
+            let mut dirty: bool = false;
+            for codeword in raw.iter_mut() {
+                match decode(*codeword) {
+                    Some((nibble, true)) => {
+                        *codeword = CODEWORDS[usize::from(nibble)];
+                        corrected += 1;
+                        dirty = true;
+                    }
+                    Some((_, false)) => (),
+                    None => corrupted = true,
+                }
+            }
+            if dirty {
+                self.write(start, raw)?;
+            }
+        }
+        if corrupted {
+            Err(Error::Corrupted)
+        } else {
+            Ok(corrected)
+        }
+    }
+}
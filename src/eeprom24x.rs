@@ -0,0 +1,174 @@
+//! Compatibility layer for the [eeprom24x] API.
+//!
+//! The [`Eeprom24xTrait`] trait mirrors the method names and signatures of
+//! the eeprom24x crate, so code written for I2C EEPROMs can be shared with the
+//! SPI EEPROMs supported by this crate.
+//!
+//! Addresses are `u32`, and accesses outside of the array return
+//! [`Error::OutOfBounds`].
+//!
+//! As in eeprom24x, writes do not wait for the write cycle to complete, even
+//! on drivers created with
+//! [`new_with_delay`](crate::Eeprom25aa02e48::new_with_delay).
+//! Callers must wait [`WRITE_CYCLE_TIME_MS`](crate::WRITE_CYCLE_TIME_MS)
+//! after each write before accessing the EEPROM again.
+//!
+//! # Example
+//!
+//! ```
+//! # use eeprom25aa02e48::instruction;
+//! # use embedded_hal_mock::eh1 as hal;
+//! # let spi = hal::spi::Mock::new(&[
+//! #   hal::spi::Transaction::transaction_start(),
+//! #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+//! #   hal::spi::Transaction::read_vec(vec![0x02, 0x12, 0x34]),
+//! #   hal::spi::Transaction::transaction_end(),
+//! # ]);
+//! use eeprom25aa02e48::{eeprom24x::Eeprom24xTrait, Eeprom25aa02e48};
+//!
+//! fn read_record<E: Eeprom24xTrait>(eeprom: &mut E, buf: &mut [u8]) -> Result<(), E::Error> {
+//!     eeprom.read_data(0x10, buf)
+//! }
+//!
+//! let mut eeprom = Eeprom25aa02e48::new(spi);
+//! let mut buf: [u8; 3] = [0; 3];
+//! read_record(&mut eeprom, &mut buf)?;
+//! assert_eq!(buf, [0x02, 0x12, 0x34]);
+//! # let mut spi = eeprom.free(); spi.done();
+//! # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+//! ```
+//!
+//! [eeprom24x]: https://github.com/eldruin/eeprom24x-rs
+
+use crate::{Eeprom25aa02, Eeprom25x, Error, Transport, Variant, ARRAY_SIZE, PAGE_SIZE};
+use embedded_hal::delay::DelayNs;
+
+/// EEPROM methods with the same names and signatures as eeprom24x.
+pub trait Eeprom24xTrait {
+    /// Error type.
+    type Error;
+
+    /// Write a single byte at the address.
+    ///
+    /// This does not wait for the write cycle to complete.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x42]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// # let delay = hal::delay::NoopDelay::new();
+    /// # let mut timer = hal::delay::NoopDelay::new();
+    /// use eeprom25aa02e48::{eeprom24x::Eeprom24xTrait, Eeprom25aa02e48, WRITE_CYCLE_TIME_MS};
+    /// use embedded_hal::delay::DelayNs;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new_with_delay(spi, delay);
+    /// Eeprom24xTrait::write_byte(&mut eeprom, 0x10, 0x42)?;
+    /// timer.delay_ms(WRITE_CYCLE_TIME_MS);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Self::Error>;
+
+    /// Read a single byte at the address.
+    fn read_byte(&mut self, address: u32) -> Result<u8, Self::Error>;
+
+    /// Read starting at the address into the buffer.
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Write up to a page starting at the address.
+    ///
+    /// The data may start anywhere within the page, but may not cross into
+    /// the next page.
+    /// This does not wait for the write cycle to complete.
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Page size in bytes.
+    fn page_size(&self) -> usize;
+}
+
+/// Check that an access lies entirely within an array of `capacity` bytes.
+fn check_bounds<E>(address: u32, len: usize, capacity: usize) -> Result<usize, Error<E>> {
+    let address: usize = usize::try_from(address).map_err(|_| Error::OutOfBounds)?;
+    match address.checked_add(len) {
+        Some(end) if end <= capacity => Ok(address),
+        _ => Err(Error::OutOfBounds),
+    }
+}
+
+impl<SPI, V, D> Eeprom24xTrait for Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    type Error = Error<SPI::Error>;
+
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Self::Error> {
+        Eeprom24xTrait::write_page(self, address, &[data])
+    }
+
+    fn read_byte(&mut self, address: u32) -> Result<u8, Self::Error> {
+        let address: usize = check_bounds(address, 1, ARRAY_SIZE)?;
+        Eeprom25aa02::read_byte(self, address as u8)
+    }
+
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Self::Error> {
+        let address: usize = check_bounds(address, data.len(), ARRAY_SIZE)?;
+        self.read(address as u8, data)
+    }
+
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Self::Error> {
+        let address: usize = check_bounds(address, data.len(), ARRAY_SIZE)?;
+        if address % usize::from(PAGE_SIZE) + data.len() > usize::from(PAGE_SIZE) {
+            return Err(Error::DataTooLong);
+        }
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.write_within_page(address as u8, data)
+    }
+
+    fn page_size(&self) -> usize {
+        PAGE_SIZE.into()
+    }
+}
+
+impl<SPI, const CAPACITY: usize, const PAGE_SIZE: usize, const ADDRESS_BYTES: usize> Eeprom24xTrait
+    for Eeprom25x<SPI, CAPACITY, PAGE_SIZE, ADDRESS_BYTES>
+where
+    SPI: Transport,
+{
+    type Error = Error<SPI::Error>;
+
+    fn write_byte(&mut self, address: u32, data: u8) -> Result<(), Self::Error> {
+        Eeprom25x::write_page(self, address, &[data])
+    }
+
+    fn read_byte(&mut self, address: u32) -> Result<u8, Self::Error> {
+        let mut buf: [u8; 1] = [0];
+        self.read(address, &mut buf)?;
+        Ok(buf[0])
+    }
+
+    fn read_data(&mut self, address: u32, data: &mut [u8]) -> Result<(), Self::Error> {
+        self.read(address, data)
+    }
+
+    fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Self::Error> {
+        Eeprom25x::write_page(self, address, data)
+    }
+
+    fn page_size(&self) -> usize {
+        PAGE_SIZE
+    }
+}
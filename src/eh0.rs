@@ -0,0 +1,155 @@
+//! Compatibility with embedded-hal 0.2.
+//!
+//! HALs that have not migrated to embedded-hal 1.0 only provide the 0.2
+//! blocking SPI traits, and leave the chip select pin to the driver.
+//! [`Eh0Device`] combines the two into a [`Transport`].
+
+use crate::{NoDelay, Transport};
+use eh0::{
+    blocking::spi::{Transfer, Write},
+    digital::v2::OutputPin,
+};
+use embedded_hal::{
+    delay::DelayNs,
+    spi::{ErrorKind, Operation},
+};
+
+/// embedded-hal 0.2 transport error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Eh0Error<S, P> {
+    /// SPI bus error.
+    Spi(S),
+    /// Chip select pin error.
+    Pin(P),
+}
+
+impl<S, P> embedded_hal::spi::Error for Eh0Error<S, P>
+where
+    S: core::fmt::Debug,
+    P: core::fmt::Debug,
+{
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Eh0Error::Spi(_) => ErrorKind::Other,
+            Eh0Error::Pin(_) => ErrorKind::ChipSelectFault,
+        }
+    }
+}
+
+/// EEPROM transport over an embedded-hal 0.2 SPI bus and chip select pin.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh0 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::write(vec![instruction::READ, 0x10]),
+/// #   hal::spi::Transaction::transfer(vec![0, 0], vec![0x12, 0x34]),
+/// # ]);
+/// # let cs = hal::digital::Mock::new(&[
+/// #   hal::digital::Transaction::set(hal::digital::State::Low),
+/// #   hal::digital::Transaction::set(hal::digital::State::High),
+/// # ]);
+/// use eeprom25aa02e48::{eh0::Eh0Device, Eeprom25aa02e48};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(Eh0Device::new(spi, cs));
+/// let mut buf: [u8; 2] = [0; 2];
+/// eeprom.read(0x10, &mut buf)?;
+/// assert_eq!(buf, [0x12, 0x34]);
+/// # let (mut spi, mut cs, _) = eeprom.free().free(); spi.done(); cs.done();
+/// # Ok::<(), eeprom25aa02e48::Error<eeprom25aa02e48::eh0::Eh0Error<hal::MockError, hal::MockError>>>(())
+/// ```
+#[derive(Debug)]
+pub struct Eh0Device<SPI, CS, D = NoDelay> {
+    spi: SPI,
+    cs: CS,
+    delay: D,
+}
+
+impl<SPI, CS> Eh0Device<SPI, CS, NoDelay> {
+    /// Creates a new transport from a SPI bus and chip select pin.
+    ///
+    /// Delay operations are ignored, use [`new_with_delay`](Self::new_with_delay)
+    /// when the EEPROM is in half-duplex mode.
+    /// embedded-hal 0.2 has no way to release the data line, so half-duplex
+    /// mode also requires a bus that does not drive MOSI while reading.
+    #[inline]
+    pub fn new(spi: SPI, cs: CS) -> Self {
+        Eh0Device {
+            spi,
+            cs,
+            delay: NoDelay,
+        }
+    }
+}
+
+impl<SPI, CS, D> Eh0Device<SPI, CS, D> {
+    /// Creates a new transport from a SPI bus, chip select pin, and delay.
+    #[inline]
+    pub fn new_with_delay(spi: SPI, cs: CS, delay: D) -> Self {
+        Eh0Device { spi, cs, delay }
+    }
+
+    /// Free the SPI bus, chip select pin, and delay.
+    #[inline]
+    pub fn free(self) -> (SPI, CS, D) {
+        (self.spi, self.cs, self.delay)
+    }
+}
+
+impl<SPI, CS, D, E> Eh0Device<SPI, CS, D>
+where
+    SPI: Transfer<u8, Error = E> + Write<u8, Error = E>,
+    CS: OutputPin,
+    D: DelayNs,
+{
+    fn operations(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), E> {
+        for op in operations.iter_mut() {
+            match op {
+                Operation::Write(data) => self.spi.write(data)?,
+                Operation::Read(buf) => {
+                    buf.fill(0);
+                    self.spi.transfer(buf)?;
+                }
+                Operation::Transfer(read, write) => {
+                    let common: usize = read.len().min(write.len());
+                    read[..common].copy_from_slice(&write[..common]);
+                    self.spi.transfer(&mut read[..common])?;
+                    if write.len() > common {
+                        self.spi.write(&write[common..])?;
+                    } else if read.len() > common {
+                        read[common..].fill(0);
+                        self.spi.transfer(&mut read[common..])?;
+                    }
+                }
+                Operation::TransferInPlace(buf) => {
+                    self.spi.transfer(buf)?;
+                }
+                Operation::DelayNs(ns) => self.delay.delay_ns(*ns),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<SPI, CS, D, E> Transport for Eh0Device<SPI, CS, D>
+where
+    SPI: Transfer<u8, Error = E> + Write<u8, Error = E>,
+    CS: OutputPin,
+    D: DelayNs,
+    E: core::fmt::Debug,
+    CS::Error: core::fmt::Debug,
+{
+    type Error = Eh0Error<E, CS::Error>;
+
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+        self.cs.set_low().map_err(Eh0Error::Pin)?;
+        let result: Result<(), E> = self.operations(operations);
+        // deassert chip select even if the bus failed
+        let cs_result: Result<(), CS::Error> = self.cs.set_high();
+        result.map_err(Eh0Error::Spi)?;
+        cs_result.map_err(Eh0Error::Pin)
+    }
+}
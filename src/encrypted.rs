@@ -0,0 +1,186 @@
+use crate::{Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE};
+use chacha20poly1305::{
+    aead::{AeadInPlace, KeyInit},
+    ChaCha20Poly1305, Key, Nonce, Tag,
+};
+use embedded_hal::delay::DelayNs;
+use rand_core::CryptoRng;
+
+/// Size of the nonce in bytes.
+const NONCE_LEN: usize = 12;
+
+/// Size of the authentication tag in bytes.
+const TAG_LEN: usize = 16;
+
+/// Size of the length prefix, nonce, and tag in bytes.
+const OVERHEAD: usize = 1 + NONCE_LEN + TAG_LEN;
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Encrypt data with ChaCha20-Poly1305 and write it to an EEPROM memory
+    /// address.
+    ///
+    /// The data is stored as a single byte length prefix, the nonce, the
+    /// ciphertext, and the authentication tag, which is 29 bytes more than
+    /// the data, and may span multiple pages.
+    /// The address is authenticated with the data, so a blob copied to
+    /// another address fails to decrypt.
+    ///
+    /// A fresh random nonce is drawn from `rng` for every write, a nonce must
+    /// never be reused with the same key.
+    ///
+    /// `data` is encrypted in place, and holds the ciphertext on return.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if the encrypted data does not fit in
+    /// the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "chacha20poly1305")] {
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # fn read(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+    /// #     hal::spi::Transaction::read_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # fn write(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # let mut blob: Vec<u8> = vec![0x07];
+    /// # blob.extend([0x3B, 0x76, 0xB1, 0xEC, 0x27, 0x62, 0x9D, 0xD8, 0x13, 0x4E, 0x89, 0xC4]);
+    /// # blob.extend([
+    /// #   0xA4, 0xD7, 0x7A, 0x54, 0xC6, 0xC8, 0x04, 0x19, 0x84, 0x4D, 0x37, 0x54,
+    /// #   0x06, 0xC3, 0xB7, 0xE0, 0x70, 0xAF, 0xF4, 0xD7, 0x70, 0xB0, 0xF2,
+    /// # ]);
+    /// # let mut expectations = vec![];
+    /// # expectations.extend(write(0x40, blob[..16].to_vec()));
+    /// # expectations.extend(write(0x50, blob[16..32].to_vec()));
+    /// # expectations.extend(write(0x60, blob[32..].to_vec()));
+    /// # for _ in 0..2 {
+    /// #   expectations.extend(read(0x40, blob[..13].to_vec()));
+    /// #   expectations.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::READ, 0x4D]),
+    /// #     hal::spi::Transaction::read_vec(blob[13..20].to_vec()),
+    /// #     hal::spi::Transaction::read_vec(blob[20..].to_vec()),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// # struct Rng(u8);
+    /// # impl rand_core::RngCore for Rng {
+    /// #     fn next_u32(&mut self) -> u32 { rand_core::impls::next_u32_via_fill(self) }
+    /// #     fn next_u64(&mut self) -> u64 { rand_core::impls::next_u64_via_fill(self) }
+    /// #     fn fill_bytes(&mut self, dst: &mut [u8]) {
+    /// #         dst.iter_mut().for_each(|b| { self.0 = self.0.wrapping_add(0x3B); *b = self.0 });
+    /// #     }
+    /// # }
+    /// # impl rand_core::CryptoRng for Rng {}
+    /// # let mut rng = Rng(0);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let key: [u8; 32] = core::array::from_fn(|n| n as u8);
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let mut data: [u8; 7] = *b"hunter2";
+    /// eeprom.write_encrypted(0x40, &key, &mut rng, &mut data)?;
+    ///
+    /// let mut buf: [u8; 16] = [0; 16];
+    /// let len: usize = eeprom.read_encrypted(0x40, &key, &mut buf)?;
+    /// assert_eq!(&buf[..len], b"hunter2");
+    ///
+    /// assert_eq!(
+    ///     eeprom.read_encrypted(0x40, &[0; 32], &mut buf),
+    ///     Err(Error::AuthenticationFailed)
+    /// );
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # }
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn write_encrypted<R: CryptoRng + ?Sized>(
+        &mut self,
+        address: u8,
+        key: &[u8; 32],
+        rng: &mut R,
+        data: &mut [u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        let len: usize = data.len();
+        if len + OVERHEAD > ARRAY_SIZE {
+            return Err(Error::DataTooLong);
+        }
+        let mut nonce: [u8; NONCE_LEN] = [0; NONCE_LEN];
+        rng.fill_bytes(&mut nonce);
+        let cipher: ChaCha20Poly1305 = ChaCha20Poly1305::new(Key::from_slice(key));
+        let tag: Tag = cipher
+            .encrypt_in_place_detached(Nonce::from_slice(&nonce), &[address], data)
+            .map_err(|_| Error::DataTooLong)?;
+        // checked against ARRAY_SIZE
+        self.write_parts(address, &[&[len as u8], &nonce, data, &tag])
+    }
+
+    /// Read and decrypt data written with
+    /// [`Eeprom25aa02::write_encrypted`].
+    ///
+    /// Returns the length of the data decrypted into `buf`.
+    /// `buf` holds the ciphertext if authentication fails.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Corrupted`] if the length prefix extends past the end of
+    ///   the array.
+    /// * [`Error::DataTooLong`] if `buf` is shorter than the data.
+    /// * [`Error::AuthenticationFailed`] if the key is incorrect, or the data
+    ///   was modified.
+    pub fn read_encrypted(
+        &mut self,
+        address: u8,
+        key: &[u8; 32],
+        buf: &mut [u8],
+    ) -> Result<usize, Error<SPI::Error>> {
+        let mut header: [u8; 1 + NONCE_LEN] = [0; 1 + NONCE_LEN];
+        self.read(address, &mut header)?;
+        let (len, nonce) = header.split_at(1);
+        let len: usize = len[0].into();
+        if usize::from(address) + len + OVERHEAD > ARRAY_SIZE {
+            return Err(Error::Corrupted);
+        }
+        let ciphertext: &mut [u8] = buf.get_mut(..len).ok_or(Error::DataTooLong)?;
+        let mut tag: [u8; TAG_LEN] = [0; TAG_LEN];
+        self.read_split(address + header.len() as u8, ciphertext, &mut tag)?;
+        let cipher: ChaCha20Poly1305 = ChaCha20Poly1305::new(Key::from_slice(key));
+        cipher
+            .decrypt_in_place_detached(
+                Nonce::from_slice(nonce),
+                &[address],
+                ciphertext,
+                Tag::from_slice(&tag),
+            )
+            .map_err(|_| Error::AuthenticationFailed)?;
+        Ok(len)
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{
+    address_range, memory_map::USER_AREA, page_chunks, Eeprom25aa02, Error, Transport, Variant,
+    PAGE_SIZE,
+};
+use core::ops::{Range, RangeBounds};
+use embedded_hal::delay::DelayNs;
+
+/// Patterns written by [`Eeprom25aa02::secure_erase`] before the final
+/// `0xFF` pass.
+const ERASE_PATTERNS: [u8; 2] = [0x55, 0xAA];
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Returns the address of the first byte in `range` that is not
+    /// `value`.
+    fn find_mismatch(
+        &mut self,
+        range: Range<usize>,
+        value: u8,
+    ) -> Result<Option<u8>, Error<SPI::Error>> {
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        for chunk in page_chunks(range) {
+            let buf: &mut [u8] = &mut buf[..chunk.len()];
+            self.read(chunk.start as u8, buf)?;
+            if let Some(position) = buf.iter().position(|&byte| byte != value) {
+                return Ok(Some((chunk.start + position) as u8));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Fill a range with `value` and verify it.
+    fn fill_verified(&mut self, range: Range<usize>, value: u8) -> Result<(), Error<SPI::Error>> {
+        self.fill(range.start as u8, range.len(), value)?;
+        match self.find_mismatch(range.clone(), value)? {
+            Some(address) => Err(Error::VerifyFailed {
+                offset: usize::from(address) - range.start,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Overwrite a range of addresses several times before erasing it.
+    ///
+    /// Each pass fills the range with alternating `0x55` and `0xAA`
+    /// patterns, and a final pass fills it with `0xFF`.
+    /// Every pass is verified before the next begins.
+    /// This is intended for decommissioning devices that stored secrets in
+    /// the user area.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Range of addresses to erase.
+    /// * `passes` - Number of pattern passes before the final `0xFF` pass.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut expectations = vec![];
+    /// # for value in [0x55, 0xAA, 0xFF] {
+    /// #   expectations.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x20]),
+    /// #     hal::spi::Transaction::write_vec(vec![value; 8]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::READ, 0x20]),
+    /// #     hal::spi::Transaction::read_vec(vec![value; 8]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.secure_erase(0x20..0x28, 2)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ProtectedRegion`] if the range overlaps the
+    ///   write-protected upper quarter of the array.
+    /// * [`Error::VerifyFailed`] if a pass did not verify, with the offset
+    ///   relative to the start of the range.
+    pub fn secure_erase(
+        &mut self,
+        range: impl RangeBounds<u8>,
+        passes: usize,
+    ) -> Result<(), Error<SPI::Error>> {
+        let range: Range<usize> = address_range(range);
+        if range.is_empty() {
+            return Ok(());
+        }
+        for pattern in ERASE_PATTERNS.iter().cycle().take(passes) {
+            self.fill_verified(range.clone(), *pattern)?;
+        }
+        self.fill_verified(range, 0xFF)
+    }
+
+    /// Restore the user area to the erased state, filling it with `0xFF`.
+    ///
+    /// This erases [`USER_AREA`], from 0x00 to 0xBF.
+    /// The protected upper quarter of the array, which holds the node
+    /// address, is never written.
+    ///
+    /// This is the same as [`erase_all`](Eeprom25aa02::erase_all).
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut expectations = vec![];
+    /// # for address in (0x00..0xC0).step_by(16) {
+    /// #   expectations.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(vec![0xFF; 16]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.factory_reset()?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[inline]
+    pub fn factory_reset(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.erase_all()
+    }
+
+    /// Restore a range of the user area to the erased state, filling it with
+    /// `0xFF`.
+    ///
+    /// See [`Eeprom25aa02::factory_reset`].
+    ///
+    /// # Example
+    ///
+    /// Keep calibration data stored in the first page.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut expectations = vec![];
+    /// # for address in (0x10..0xC0).step_by(16) {
+    /// #   expectations.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(vec![0xFF; 16]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.factory_reset_range(0x10..), Err(Error::ProtectedRegion));
+    /// eeprom.factory_reset_range(0x10..0xC0)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ProtectedRegion`] without erasing anything if the
+    /// range overlaps the protected upper quarter of the array.
+    pub fn factory_reset_range(
+        &mut self,
+        range: impl RangeBounds<u8>,
+    ) -> Result<(), Error<SPI::Error>> {
+        let range: Range<usize> = address_range(range);
+        if range.end > usize::from(*USER_AREA.end()) + 1 {
+            return Err(Error::ProtectedRegion);
+        }
+        self.fill(range.start as u8, range.len(), 0xFF)
+    }
+
+    /// Check that a range of addresses is blank, reading as `0xFF`.
+    ///
+    /// Returns the address of the first byte that is not blank, or `None`
+    /// if the entire range is blank.
+    /// The range is read in chunks of up to one page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x08]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xFF; 8]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xFF, 0xFF, 0x7F, 0xFF]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.blank_check(0x08..0x14)?, Some(0x12));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn blank_check(
+        &mut self,
+        range: impl RangeBounds<u8>,
+    ) -> Result<Option<u8>, Error<SPI::Error>> {
+        self.find_mismatch(address_range(range), 0xFF)
+    }
+}
@@ -0,0 +1,518 @@
+use crate::{EUI48_BYTES, EUI64_BYTES};
+use core::{fmt, net::Ipv6Addr, str::FromStr};
+
+/// Length of a formatted [`Eui48`], such as `00:04:A3:12:34:56`.
+const FORMATTED_LEN: usize = EUI48_BYTES * 3 - 1;
+
+/// Organizationally unique identifiers assigned to Microchip Technology.
+///
+/// Microchip obtains new OUIs as blocks are exhausted, so this list may not
+/// include OUIs used by parts manufactured after this crate was released.
+pub const MICROCHIP_OUIS: &[[u8; 3]] = &[
+    [0x00, 0x04, 0xA3],
+    [0x00, 0x1E, 0xC0],
+    [0x04, 0x91, 0x62],
+    [0x54, 0x10, 0xEC],
+    [0x80, 0x1F, 0x12],
+    [0xD8, 0x80, 0x39],
+    [0xFC, 0x0F, 0xE7],
+];
+
+/// Separator between octets for [`Eui48::format_into`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Separator {
+    /// Colon separated, `00:04:A3:12:34:56`.
+    #[default]
+    Colon,
+    /// Hyphen separated, `00-04-A3-12-34-56`.
+    Hyphen,
+}
+
+impl Separator {
+    const fn byte(self) -> u8 {
+        match self {
+            Separator::Colon => b':',
+            Separator::Hyphen => b'-',
+        }
+    }
+}
+
+/// EUI-48 MAC address.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::Eui48;
+///
+/// let mac: Eui48 = "00:04:A3:12:34:56".parse()?;
+/// assert_eq!(mac, Eui48([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]));
+/// assert!(mac.is_unicast());
+/// assert!(!mac.is_locally_administered());
+/// assert_eq!(format!("{mac}"), "00:04:A3:12:34:56");
+/// # Ok::<(), eeprom25aa02e48::ParseEui48Error>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Eui48(pub [u8; EUI48_BYTES]);
+
+impl Eui48 {
+    /// Broadcast address, `FF:FF:FF:FF:FF:FF`.
+    pub const BROADCAST: Eui48 = Eui48([0xFF; EUI48_BYTES]);
+
+    /// Returns the octets of the address.
+    #[inline]
+    pub const fn octets(&self) -> [u8; EUI48_BYTES] {
+        self.0
+    }
+
+    /// Returns `true` if the individual/group bit is clear.
+    #[inline]
+    pub const fn is_unicast(&self) -> bool {
+        self.0[0] & 0x01 == 0
+    }
+
+    /// Returns `true` if the individual/group bit is set.
+    #[inline]
+    pub const fn is_multicast(&self) -> bool {
+        !self.is_unicast()
+    }
+
+    /// Returns `true` if this is the broadcast address.
+    #[inline]
+    pub const fn is_broadcast(&self) -> bool {
+        let mut n: usize = 0;
+        while n < EUI48_BYTES {
+            if self.0[n] != 0xFF {
+                return false;
+            }
+            n += 1;
+        }
+        true
+    }
+
+    /// Returns `true` if the address is all `0x00` or all `0xFF`, as read from
+    /// an erased or unprogrammed part.
+    #[inline]
+    pub const fn is_blank(&self) -> bool {
+        self.to_u64() == 0 || self.is_broadcast()
+    }
+
+    /// Returns `true` if the universal/local bit is set.
+    ///
+    /// Addresses programmed into the EEPROM from the factory are universally
+    /// administered.
+    #[inline]
+    pub const fn is_locally_administered(&self) -> bool {
+        self.0[0] & 0x02 != 0
+    }
+
+    /// Derive a locally administered unicast address from a seed.
+    ///
+    /// The seed is hashed with 64-bit FNV-1a, so the same seed always
+    /// produces the same address.
+    /// This is intended as a fallback when [`validate_eui48`] fails, using a
+    /// device unique value such as the MCU unique ID as the seed.
+    ///
+    /// [`validate_eui48`]: crate::Eeprom25aa02::validate_eui48
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// let uid: [u8; 12] = [0x1F, 0x00, 0x2A, 0x00, 0x0D, 0x51, 0x33, 0x30, 0x38, 0x36, 0x37, 0x31];
+    /// let mac: Eui48 = Eui48::local_from_seed(&uid);
+    /// assert!(mac.is_unicast());
+    /// assert!(mac.is_locally_administered());
+    /// assert_eq!(mac, Eui48::local_from_seed(&uid));
+    /// ```
+    pub const fn local_from_seed(seed: &[u8]) -> Self {
+        const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+        let mut hash: u64 = FNV_OFFSET_BASIS;
+        let mut n: usize = 0;
+        while n < seed.len() {
+            hash ^= seed[n] as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+            n += 1;
+        }
+
+        Eui48::from_u64(hash).into_local_unicast()
+    }
+
+    /// Generate a random locally administered unicast address.
+    ///
+    /// This is useful for development boards without an EUI-48 EEPROM
+    /// populated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    /// # struct Rng(u8);
+    /// # impl rand_core::RngCore for Rng {
+    /// #     fn next_u32(&mut self) -> u32 { rand_core::impls::next_u32_via_fill(self) }
+    /// #     fn next_u64(&mut self) -> u64 { rand_core::impls::next_u64_via_fill(self) }
+    /// #     fn fill_bytes(&mut self, dst: &mut [u8]) {
+    /// #         dst.iter_mut().for_each(|b| { self.0 = self.0.wrapping_add(0x3B); *b = self.0 });
+    /// #     }
+    /// # }
+    /// # let mut rng = Rng(0);
+    ///
+    /// let mac: Eui48 = Eui48::random_local(&mut rng);
+    /// assert!(mac.is_unicast());
+    /// assert!(mac.is_locally_administered());
+    /// ```
+    #[cfg(feature = "rand_core")]
+    pub fn random_local<R: rand_core::RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut eui48: Eui48 = Eui48::default();
+        rng.fill_bytes(&mut eui48.0);
+        eui48.into_local_unicast()
+    }
+
+    /// Clear the individual/group bit, and set the universal/local bit.
+    const fn into_local_unicast(mut self) -> Self {
+        self.0[0] = (self.0[0] & !0x01) | 0x02;
+        self
+    }
+
+    /// Returns the organizationally unique identifier, the first three octets
+    /// of the address.
+    #[inline]
+    pub const fn oui(&self) -> [u8; 3] {
+        let [a, b, c, _, _, _] = self.0;
+        [a, b, c]
+    }
+
+    /// Returns `true` if the OUI is in [`MICROCHIP_OUIS`].
+    ///
+    /// A factory programmed 25AA02E48 should always have a Microchip OUI,
+    /// other values indicate a counterfeit or reprogrammed part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// let mac: Eui48 = Eui48([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// assert_eq!(mac.oui(), [0x00, 0x04, 0xA3]);
+    /// assert!(mac.is_microchip_oui());
+    /// assert!(!Eui48::BROADCAST.is_microchip_oui());
+    /// ```
+    pub fn is_microchip_oui(&self) -> bool {
+        MICROCHIP_OUIS.contains(&self.oui())
+    }
+
+    /// Convert the address to an integer.
+    ///
+    /// The first octet is the most significant, and the upper 16 bits are
+    /// zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// let mac: Eui48 = Eui48([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// assert_eq!(mac.to_u64(), 0x0004_A312_3456);
+    /// assert_eq!(Eui48::from_u64(0x0004_A312_3456), mac);
+    /// ```
+    #[inline]
+    pub const fn to_u64(&self) -> u64 {
+        let [a, b, c, d, e, f] = self.0;
+        u64::from_be_bytes([0, 0, a, b, c, d, e, f])
+    }
+
+    /// Create an address from an integer.
+    ///
+    /// The most significant of the lower 48 bits is the first octet, and the
+    /// upper 16 bits are ignored.
+    #[inline]
+    pub const fn from_u64(value: u64) -> Self {
+        let [_, _, a, b, c, d, e, f] = value.to_be_bytes();
+        Eui48([a, b, c, d, e, f])
+    }
+
+    /// Format the address as upper case hex into a buffer, without using
+    /// `core::fmt`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::{Eui48, Separator};
+    ///
+    /// let mac: Eui48 = Eui48([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// let mut buf: [u8; 17] = [0; 17];
+    /// assert_eq!(mac.format_into(&mut buf, Separator::Hyphen), "00-04-A3-12-34-56");
+    /// ```
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8; FORMATTED_LEN],
+        separator: Separator,
+    ) -> &'a str {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        for (n, octet) in self.0.iter().enumerate() {
+            let start: usize = n * 3;
+            buf[start] = HEX[usize::from(octet >> 4)];
+            buf[start + 1] = HEX[usize::from(octet & 0xF)];
+            if start + 2 < FORMATTED_LEN {
+                buf[start + 2] = separator.byte();
+            }
+        }
+        // always ASCII
+        core::str::from_utf8(buf).unwrap_or_default()
+    }
+
+    /// Derive an EUI-64 by inserting `FF:FE` between the OUI and the
+    /// extension identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// let mac: Eui48 = Eui48([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// assert_eq!(
+    ///     mac.to_eui64(),
+    ///     [0x00, 0x04, 0xA3, 0xFF, 0xFE, 0x12, 0x34, 0x56]
+    /// );
+    /// ```
+    #[inline]
+    pub const fn to_eui64(&self) -> [u8; EUI64_BYTES] {
+        let [a, b, c, d, e, f] = self.0;
+        [a, b, c, 0xFF, 0xFE, d, e, f]
+    }
+
+    /// Derive the modified EUI-64 interface identifier, as used by IPv6
+    /// stateless address autoconfiguration.
+    ///
+    /// This is [`to_eui64`](Self::to_eui64) with the universal/local bit
+    /// inverted, see [RFC 4291] appendix A.
+    ///
+    /// [RFC 4291]: https://www.rfc-editor.org/rfc/rfc4291#appendix-A
+    #[inline]
+    pub const fn to_modified_eui64(&self) -> [u8; EUI64_BYTES] {
+        let mut eui64: [u8; EUI64_BYTES] = self.to_eui64();
+        eui64[0] ^= 0x02;
+        eui64
+    }
+
+    /// Derive the IPv6 link-local address, `fe80::/64` with the modified
+    /// EUI-64 interface identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::net::Ipv6Addr;
+    /// use eeprom25aa02e48::Eui48;
+    ///
+    /// let mac: Eui48 = Eui48([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+    /// let addr: Ipv6Addr = mac.ipv6_link_local();
+    /// assert_eq!(addr, "fe80::204:a3ff:fe12:3456".parse::<Ipv6Addr>().unwrap());
+    /// ```
+    #[inline]
+    pub const fn ipv6_link_local(&self) -> Ipv6Addr {
+        let [a, b, c, d, e, f, g, h] = self.to_modified_eui64();
+        Ipv6Addr::new(
+            0xFE80,
+            0,
+            0,
+            0,
+            u16::from_be_bytes([a, b]),
+            u16::from_be_bytes([c, d]),
+            u16::from_be_bytes([e, f]),
+            u16::from_be_bytes([g, h]),
+        )
+    }
+}
+
+impl From<[u8; EUI48_BYTES]> for Eui48 {
+    #[inline]
+    fn from(octets: [u8; EUI48_BYTES]) -> Self {
+        Eui48(octets)
+    }
+}
+
+impl From<Eui48> for [u8; EUI48_BYTES] {
+    #[inline]
+    fn from(eui48: Eui48) -> Self {
+        eui48.0
+    }
+}
+
+impl AsRef<[u8]> for Eui48 {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Eui48 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Formats the address as colon separated upper case hex, for example
+/// `00:04:A3:12:34:56`.
+impl fmt::Display for Eui48 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf: [u8; FORMATTED_LEN] = [0; FORMATTED_LEN];
+        f.write_str(self.format_into(&mut buf, Separator::Colon))
+    }
+}
+
+#[cfg(feature = "smoltcp")]
+impl From<Eui48> for smoltcp::wire::EthernetAddress {
+    #[inline]
+    fn from(eui48: Eui48) -> Self {
+        smoltcp::wire::EthernetAddress(eui48.0)
+    }
+}
+
+#[cfg(feature = "smoltcp")]
+impl From<smoltcp::wire::EthernetAddress> for Eui48 {
+    #[inline]
+    fn from(addr: smoltcp::wire::EthernetAddress) -> Self {
+        Eui48(addr.0)
+    }
+}
+
+/// Converts the address into an Embassy hardware address, for use with
+/// `embassy_net::driver::Driver::hardware_address`.
+///
+/// For the smoltcp `EthernetAddress` re-exported by embassy-net, enable the
+/// `smoltcp` feature.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::Eui48;
+/// use embassy_net_driver::HardwareAddress;
+///
+/// let mac: Eui48 = Eui48([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+/// assert_eq!(
+///     HardwareAddress::from(mac),
+///     HardwareAddress::Ethernet([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56])
+/// );
+/// ```
+#[cfg(feature = "embassy-net")]
+impl From<Eui48> for embassy_net_driver::HardwareAddress {
+    #[inline]
+    fn from(eui48: Eui48) -> Self {
+        embassy_net_driver::HardwareAddress::Ethernet(eui48.0)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Eui48 {
+    fn format(&self, f: defmt::Formatter) {
+        let [a, b, c, d, e, g] = self.0;
+        defmt::write!(
+            f,
+            "{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}",
+            a,
+            b,
+            c,
+            d,
+            e,
+            g
+        )
+    }
+}
+
+/// Serializes as a colon separated string for human readable formats, and as
+/// six bytes otherwise.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::Eui48;
+///
+/// let mac: Eui48 = Eui48([0x00, 0x04, 0xA3, 0x12, 0x34, 0x56]);
+/// let json: String = serde_json::to_string(&mac).unwrap();
+/// assert_eq!(json, r#""00:04:A3:12:34:56""#);
+/// assert_eq!(serde_json::from_str::<Eui48>(&json).unwrap(), mac);
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for Eui48 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let mut buf: [u8; FORMATTED_LEN] = [0; FORMATTED_LEN];
+            serializer.serialize_str(self.format_into(&mut buf, Separator::Colon))
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Eui48 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Eui48;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an EUI-48 address such as 00:04:A3:12:34:56")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Visitor)
+        } else {
+            <[u8; EUI48_BYTES]>::deserialize(deserializer).map(Eui48)
+        }
+    }
+}
+
+/// Error returned when parsing an [`Eui48`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParseEui48Error;
+
+impl fmt::Display for ParseEui48Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid EUI-48 address syntax")
+    }
+}
+
+impl core::error::Error for ParseEui48Error {}
+
+/// Parses six hex octets separated by `:` or `-`, for example
+/// `00:04:A3:12:34:56` or `00-04-a3-12-34-56`.
+impl FromStr for Eui48 {
+    type Err = ParseEui48Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes: &[u8] = s.as_bytes();
+        if bytes.len() != FORMATTED_LEN {
+            return Err(ParseEui48Error);
+        }
+        let separator: u8 = bytes[2];
+        if separator != b':' && separator != b'-' {
+            return Err(ParseEui48Error);
+        }
+
+        let mut octets: [u8; EUI48_BYTES] = [0; EUI48_BYTES];
+        for (n, octet) in octets.iter_mut().enumerate() {
+            let start: usize = n * 3;
+            if n != 0 && bytes[start - 1] != separator {
+                return Err(ParseEui48Error);
+            }
+            let hex: &str = s.get(start..start + 2).ok_or(ParseEui48Error)?;
+            if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(ParseEui48Error);
+            }
+            *octet = u8::from_str_radix(hex, 16).map_err(|_| ParseEui48Error)?;
+        }
+        Ok(Eui48(octets))
+    }
+}
@@ -0,0 +1,426 @@
+use crate::{bus::Bus, instruction, Error, NoDelay, StatusRegister, Transport};
+use core::{num::NonZeroU8, ops::Range};
+use embedded_hal::{delay::DelayNs, spi::Operation};
+
+/// Bit in the instruction holding the ninth address bit on parts with a
+/// single address byte and more than 256 bytes.
+const A8: u8 = 1 << 3;
+
+/// Driver for the wider 25AA/25LC SPI EEPROM family.
+///
+/// The geometry of the part is selected with const generics:
+///
+/// * `CAPACITY` - Size of the array in bytes.
+/// * `PAGE_SIZE` - Size of a write page in bytes.
+/// * `ADDRESS_BYTES` - Number of address bytes sent after the instruction.
+///
+/// Parts with a single address byte and a 512 byte array, such as the
+/// 25AA040A, send the ninth address bit in the instruction.
+///
+/// The geometry is checked when the driver is created:
+///
+/// * `ADDRESS_BYTES` must be 1 or 2.
+/// * `CAPACITY` must be addressable, at most 512 bytes with one address byte
+///   and 64 KiB with two.
+/// * `PAGE_SIZE` must be a power of two that divides `CAPACITY`.
+///
+/// Type aliases are provided for common parts, for example
+/// [`Eeprom25aa040a`] and [`Eeprom25aa320a`].
+///
+/// The driver shares its SPI handling with [`Eeprom25aa02`](crate::Eeprom25aa02):
+/// half-duplex buses, an owned delay for write cycles, automatic recovery,
+/// bus health statistics and skipping identical bytes work the same way.
+/// There is no write policy, which guards the node address of the 25AA02E48
+/// and 25AA02E64.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x01, 0x23]),
+/// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::Eeprom25aa160a;
+///
+/// let mut eeprom = Eeprom25aa160a::new(spi);
+/// assert_eq!(eeprom.capacity(), 2048);
+///
+/// let mut buf: [u8; 2] = [0; 2];
+/// eeprom.read(0x123, &mut buf)?;
+/// assert_eq!(buf, [0x12, 0x34]);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+///
+/// Invalid geometries do not compile.
+///
+/// ```compile_fail
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[]);
+/// use eeprom25aa02e48::Eeprom25x;
+///
+/// // 1024 bytes cannot be addressed with one address byte
+/// let eeprom: Eeprom25x<_, 1024, 16, 1> = Eeprom25x::new(spi);
+/// ```
+#[derive(Debug)]
+pub struct Eeprom25x<
+    SPI,
+    const CAPACITY: usize,
+    const PAGE_SIZE: usize,
+    const ADDRESS_BYTES: usize,
+    D = NoDelay,
+> {
+    bus: Bus<SPI, D>,
+    skip_identical: bool,
+}
+
+/// Microchip 25AA040A / 25LC040A driver.
+pub type Eeprom25aa040a<SPI, D = NoDelay> = Eeprom25x<SPI, 512, 16, 1, D>;
+/// Microchip 25AA080A / 25LC080A driver.
+pub type Eeprom25aa080a<SPI, D = NoDelay> = Eeprom25x<SPI, 1024, 16, 2, D>;
+/// Microchip 25AA080B / 25LC080B driver.
+pub type Eeprom25aa080b<SPI, D = NoDelay> = Eeprom25x<SPI, 1024, 32, 2, D>;
+/// Microchip 25AA160A / 25LC160A driver.
+pub type Eeprom25aa160a<SPI, D = NoDelay> = Eeprom25x<SPI, 2048, 16, 2, D>;
+/// Microchip 25AA160B / 25LC160B driver.
+pub type Eeprom25aa160b<SPI, D = NoDelay> = Eeprom25x<SPI, 2048, 32, 2, D>;
+/// Microchip 25AA320A / 25LC320A driver.
+pub type Eeprom25aa320a<SPI, D = NoDelay> = Eeprom25x<SPI, 4096, 32, 2, D>;
+
+impl<SPI, const CAPACITY: usize, const PAGE_SIZE: usize, const ADDRESS_BYTES: usize>
+    Eeprom25x<SPI, CAPACITY, PAGE_SIZE, ADDRESS_BYTES, NoDelay>
+where
+    SPI: Transport,
+{
+    /// Creates a new driver from a SPI bus.
+    #[inline]
+    pub fn new(spi: SPI) -> Self {
+        Self::with_bus(Bus::new(spi, None, false))
+    }
+
+    /// Creates a new driver from a half-duplex (3-wire) SPI bus.
+    ///
+    /// See [`Eeprom25aa02::new_half_duplex`](crate::Eeprom25aa02::new_half_duplex).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, HALF_DUPLEX_TURNAROUND_NS};
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x0F, 0xF0]),
+    /// #   hal::spi::Transaction::delay(HALF_DUPLEX_TURNAROUND_NS),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa320a;
+    ///
+    /// let mut eeprom = Eeprom25aa320a::new_half_duplex(spi);
+    /// let mut buf: [u8; 1] = [0];
+    /// eeprom.read(0xFF0, &mut buf)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[inline]
+    pub fn new_half_duplex(spi: SPI) -> Self {
+        Self::with_bus(Bus::new(spi, None, true))
+    }
+}
+
+impl<SPI, const CAPACITY: usize, const PAGE_SIZE: usize, const ADDRESS_BYTES: usize, D>
+    Eeprom25x<SPI, CAPACITY, PAGE_SIZE, ADDRESS_BYTES, D>
+where
+    SPI: Transport,
+    D: DelayNs,
+{
+    /// Creates a new driver from a SPI bus and a delay.
+    ///
+    /// See [`Eeprom25aa02::new_with_delay`](crate::Eeprom25aa02::new_with_delay).
+    #[inline]
+    pub fn new_with_delay(spi: SPI, delay: D) -> Self {
+        Self::with_bus(Bus::new(spi, Some(delay), false))
+    }
+
+    /// Check the geometry and wrap the bus.
+    #[inline]
+    fn with_bus(bus: Bus<SPI, D>) -> Self {
+        const {
+            assert!(
+                ADDRESS_BYTES == 1 || ADDRESS_BYTES == 2,
+                "ADDRESS_BYTES must be 1 or 2"
+            );
+            assert!(
+                CAPACITY <= if ADDRESS_BYTES == 1 { 512 } else { 1 << 16 },
+                "CAPACITY is not addressable with ADDRESS_BYTES"
+            );
+            assert!(
+                PAGE_SIZE.is_power_of_two() && CAPACITY.is_multiple_of(PAGE_SIZE),
+                "PAGE_SIZE must be a power of two that divides CAPACITY"
+            );
+        };
+        Eeprom25x {
+            bus,
+            skip_identical: false,
+        }
+    }
+
+    /// Free the SPI bus from the EEPROM.
+    #[inline]
+    pub fn free(self) -> SPI {
+        self.bus.free()
+    }
+
+    /// Size of the array in bytes.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Size of a write page in bytes.
+    #[inline]
+    pub const fn page_size(&self) -> usize {
+        PAGE_SIZE
+    }
+
+    /// Number of write pages in the array.
+    #[inline]
+    pub const fn page_count(&self) -> usize {
+        CAPACITY / PAGE_SIZE
+    }
+
+    /// Bus health statistics.
+    ///
+    /// See [`Eeprom25aa02::bus_health`](crate::Eeprom25aa02::bus_health).
+    #[cfg(feature = "bus-health")]
+    #[inline]
+    pub fn bus_health(&self) -> &crate::BusHealth {
+        self.bus.health()
+    }
+
+    /// Reset the bus health statistics.
+    ///
+    /// This also clears the consecutive error count used by the automatic
+    /// recovery policy.
+    #[cfg(feature = "bus-health")]
+    #[inline]
+    pub fn reset_bus_health(&mut self) {
+        self.bus.reset_health();
+    }
+
+    /// Set the automatic recovery policy.
+    ///
+    /// See [`Eeprom25aa02::set_recovery_threshold`](crate::Eeprom25aa02::set_recovery_threshold).
+    #[inline]
+    pub fn set_recovery_threshold(&mut self, threshold: Option<NonZeroU8>) {
+        self.bus.set_recovery_threshold(threshold);
+    }
+
+    /// Skip writing bytes that already hold the data.
+    ///
+    /// See [`Eeprom25aa02::set_skip_identical`](crate::Eeprom25aa02::set_skip_identical).
+    #[inline]
+    pub fn set_skip_identical(&mut self, enabled: bool) {
+        self.skip_identical = enabled;
+    }
+
+    /// Probe the device.
+    ///
+    /// See [`Eeprom25aa02::probe`](crate::Eeprom25aa02::probe).
+    #[inline]
+    pub fn probe(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.bus.probe()
+    }
+
+    /// Recover the device after a failure.
+    ///
+    /// See [`Eeprom25aa02::recover`](crate::Eeprom25aa02::recover).
+    #[inline]
+    pub fn recover(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.bus.recover()
+    }
+
+    /// Encode an instruction and address.
+    ///
+    /// Returns the command buffer and the number of bytes to send.
+    fn command(instruction: u8, address: usize) -> ([u8; 3], usize) {
+        let mut cmd: [u8; 3] = [instruction, 0, 0];
+        if ADDRESS_BYTES == 1 {
+            if address > 0xFF {
+                cmd[0] |= A8;
+            }
+            cmd[1] = address as u8;
+        } else {
+            cmd[1] = (address >> 8) as u8;
+            cmd[2] = address as u8;
+        }
+        (cmd, 1 + ADDRESS_BYTES)
+    }
+
+    /// Check that an access lies entirely within the array.
+    fn check_bounds(address: u32, len: usize) -> Result<usize, Error<SPI::Error>> {
+        let address: usize = usize::try_from(address).map_err(|_| Error::OutOfBounds)?;
+        match address.checked_add(len) {
+            Some(end) if end <= CAPACITY => Ok(address),
+            _ => Err(Error::OutOfBounds),
+        }
+    }
+
+    /// Read from the EEPROM.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the read extends past the end of the
+    /// array.
+    pub fn read(&mut self, address: u32, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        let address: usize = Self::check_bounds(address, buf.len())?;
+        if buf.is_empty() {
+            return Ok(());
+        }
+        self.bus.begin()?;
+        let (cmd, len) = Self::command(instruction::READ, address);
+        self.bus.read(&cmd[..len], buf, &mut [])?;
+        Ok(())
+    }
+
+    /// Write a single page to the EEPROM.
+    ///
+    /// The data may start anywhere within the page, but may not cross into the
+    /// next page.
+    ///
+    /// Drivers created with [`new_with_delay`](Self::new_with_delay) wait
+    /// for the write cycle to complete before returning, other drivers do
+    /// not, see [`wait_write_complete`](Self::wait_write_complete).
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OutOfBounds`] if the write extends past the end of the array.
+    /// * [`Error::DataTooLong`] if the write crosses a page boundary.
+    pub fn write_page(&mut self, address: u32, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        let address: usize = Self::check_bounds(address, data.len())?;
+        if address % PAGE_SIZE + data.len() > PAGE_SIZE {
+            return Err(Error::DataTooLong);
+        }
+        if self.start_page_write(address, data)? {
+            self.bus.auto_wait()?;
+        }
+        Ok(())
+    }
+
+    /// Start writing data that does not cross a page boundary, without
+    /// waiting for the write cycle to complete.
+    ///
+    /// Returns `false` if no write cycle was started because there is no
+    /// data, or the data is already stored.
+    fn start_page_write(&mut self, address: usize, data: &[u8]) -> Result<bool, Error<SPI::Error>> {
+        let (address, data): (usize, &[u8]) = if self.skip_identical {
+            match self.differing_range(address, data)? {
+                Some(range) => (address + range.start, &data[range]),
+                None => return Ok(false),
+            }
+        } else {
+            (address, data)
+        };
+        if data.is_empty() {
+            return Ok(false);
+        }
+        self.bus.begin()?;
+        let (cmd, len) = Self::command(instruction::WRITE, address);
+        self.bus
+            .with_write_latch(&mut [Operation::Write(&cmd[..len]), Operation::Write(data)])?;
+        Ok(true)
+    }
+
+    /// Returns the range of `data` that differs from the array.
+    fn differing_range(
+        &mut self,
+        address: usize,
+        data: &[u8],
+    ) -> Result<Option<Range<usize>>, Error<SPI::Error>> {
+        let mut buf: [u8; PAGE_SIZE] = [0; PAGE_SIZE];
+        let buf: &mut [u8] = &mut buf[..data.len()];
+        self.read(address as u32, buf)?;
+        let differs = |(a, b): (&u8, &u8)| a != b;
+        let first: Option<usize> = buf.iter().zip(data).position(differs);
+        let last: Option<usize> = buf.iter().zip(data).rposition(differs);
+        Ok(first.zip(last).map(|(first, last)| first..last + 1))
+    }
+
+    /// Write data of any length to the EEPROM.
+    ///
+    /// The data is split into page writes, and the driver waits for each
+    /// write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut transactions = Vec::new();
+    /// # for (cmd, address) in [(instruction::WRITE, 0xFE), (instruction::WRITE | 0x08, 0x00)] {
+    /// #   transactions.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![cmd, address]),
+    /// #     hal::spi::Transaction::write_vec(vec![0xAB; 2]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&transactions);
+    /// use eeprom25aa02e48::Eeprom25aa040a;
+    ///
+    /// let mut eeprom = Eeprom25aa040a::new(spi);
+    /// eeprom.write(0xFE, &[0xAB; 4])?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the write extends past the end of the
+    /// array.
+    pub fn write(&mut self, address: u32, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        let mut address: usize = Self::check_bounds(address, data.len())?;
+        let mut data: &[u8] = data;
+        while !data.is_empty() {
+            let len: usize = (PAGE_SIZE - address % PAGE_SIZE).min(data.len());
+            let (chunk, rest) = data.split_at(len);
+            if self.start_page_write(address, chunk)? {
+                self.bus.wait_write_complete()?;
+            }
+            address += len;
+            data = rest;
+        }
+        Ok(())
+    }
+
+    /// Read the STATUS register.
+    #[inline]
+    pub fn read_status(&mut self) -> Result<StatusRegister, Error<SPI::Error>> {
+        self.bus.read_status()
+    }
+
+    /// Returns `true` if a write cycle is in progress.
+    #[inline]
+    pub fn is_busy(&mut self) -> Result<bool, Error<SPI::Error>> {
+        self.bus.is_busy()
+    }
+
+    /// Wait for an internal write cycle to complete.
+    ///
+    /// See [`Eeprom25aa02::wait_write_complete`](crate::Eeprom25aa02::wait_write_complete).
+    #[inline]
+    pub fn wait_write_complete(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.bus.wait_write_complete()
+    }
+}
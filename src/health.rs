@@ -0,0 +1,66 @@
+use embedded_hal::spi::{Error, ErrorKind};
+
+/// Bus health statistics.
+///
+/// Returned by [`Eeprom25aa02e48::bus_health`](crate::Eeprom25aa02e48::bus_health).
+///
+/// Every SPI transaction issued by the driver is counted, including the
+/// write enable and write disable instructions.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BusHealth {
+    transactions: u32,
+    errors: u32,
+    consecutive_errors: u32,
+    last_error: Option<ErrorKind>,
+}
+
+impl BusHealth {
+    pub(crate) fn record<E: Error>(&mut self, result: &Result<(), E>) {
+        self.transactions = self.transactions.saturating_add(1);
+        match result {
+            Ok(()) => self.consecutive_errors = 0,
+            Err(e) => {
+                self.errors = self.errors.saturating_add(1);
+                self.consecutive_errors = self.consecutive_errors.saturating_add(1);
+                self.last_error = Some(e.kind());
+            }
+        }
+    }
+
+    /// Total number of SPI transactions.
+    #[inline]
+    pub const fn transactions(&self) -> u32 {
+        self.transactions
+    }
+
+    /// Total number of failed SPI transactions.
+    #[inline]
+    pub const fn errors(&self) -> u32 {
+        self.errors
+    }
+
+    /// Number of SPI transactions that have failed since the last successful
+    /// transaction.
+    #[inline]
+    pub const fn consecutive_errors(&self) -> u32 {
+        self.consecutive_errors
+    }
+
+    /// Kind of the most recent SPI error, if any.
+    #[inline]
+    pub const fn last_error(&self) -> Option<ErrorKind> {
+        self.last_error
+    }
+
+    /// Ratio of failed transactions to total transactions, from 0.0 to 1.0.
+    ///
+    /// Returns 0.0 if no transactions have been issued.
+    pub fn error_rate(&self) -> f32 {
+        if self.transactions == 0 {
+            0.0
+        } else {
+            self.errors as f32 / self.transactions as f32
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{Eeprom25aa02, Error, NoDelay, Transport, Variant, ARRAY_SIZE, E48};
+use embedded_hal::delay::DelayNs;
+use embedded_io::{ErrorKind, ErrorType, Read, Seek, SeekFrom, Write};
+
+impl<E: embedded_hal::spi::Error> embedded_io::Error for Error<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NoDevice => ErrorKind::NotConnected,
+            Error::Recovered => ErrorKind::Interrupted,
+            Error::Timeout => ErrorKind::TimedOut,
+            Error::Eui48Mismatch
+            | Error::BlankEui48
+            | Error::VerifyFailed { .. }
+            | Error::Corrupted
+            | Error::UnsupportedVersion
+            | Error::AuthenticationFailed => ErrorKind::InvalidData,
+            Error::OutOfBounds
+            | Error::DataTooLong
+            | Error::NotPageAligned
+            | Error::Serialization => ErrorKind::InvalidInput,
+            Error::WriteDenied | Error::ProtectedRegion => ErrorKind::PermissionDenied,
+            Error::PartitionConflict => ErrorKind::AlreadyExists,
+            Error::PartitionNotFound => ErrorKind::NotFound,
+            Error::Spi(_) | Error::Unrecoverable => ErrorKind::Other,
+        }
+    }
+}
+
+/// Stream adapter that treats the EEPROM array as a 256 byte file.
+///
+/// Implements the [`embedded_io`] [`Read`], [`Write`], and [`Seek`] traits.
+/// Reads return end of file at the end of the array, and writes past the end
+/// of the array return [`Error::OutOfBounds`].
+///
+/// Writes are split into pages, and the driver waits for each write cycle,
+/// see [write cycles](crate#write-cycles).
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0xFC]),
+/// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, EepromCursor};
+/// use embedded_io::{Read, Seek, SeekFrom};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// let mut cursor = EepromCursor::new(&mut eeprom);
+/// cursor.seek(SeekFrom::End(-4))?;
+///
+/// let mut buf: [u8; 8] = [0; 8];
+/// assert_eq!(cursor.read(&mut buf)?, 4);
+/// assert_eq!(buf[..4], [0x12, 0x34, 0x56, 0x78]);
+/// assert_eq!(cursor.read(&mut buf)?, 0);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct EepromCursor<'a, SPI, V = E48, D = NoDelay> {
+    eeprom: &'a mut Eeprom25aa02<SPI, V, D>,
+    position: usize,
+}
+
+impl<'a, SPI, V, D> EepromCursor<'a, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Creates a new cursor at the start of the array.
+    pub fn new(eeprom: &'a mut Eeprom25aa02<SPI, V, D>) -> Self {
+        EepromCursor {
+            eeprom,
+            position: 0,
+        }
+    }
+
+    /// Returns the current position of the cursor.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<SPI, V, D> ErrorType for EepromCursor<'_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    type Error = Error<SPI::Error>;
+}
+
+impl<SPI, V, D> Read for EepromCursor<'_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let len: usize = buf.len().min(ARRAY_SIZE - self.position);
+        if len != 0 {
+            self.eeprom.read(self.position as u8, &mut buf[..len])?;
+            self.position += len;
+        }
+        Ok(len)
+    }
+}
+
+impl<SPI, V, D> Write for EepromCursor<'_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let len: usize = buf.len().min(ARRAY_SIZE - self.position);
+        if len == 0 {
+            return Err(Error::OutOfBounds);
+        }
+        self.eeprom.write(self.position as u8, &buf[..len])?;
+        self.position += len;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<SPI, V, D> Seek for EepromCursor<'_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error> {
+        let position: Option<u64> = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => (ARRAY_SIZE as u64).checked_add_signed(offset),
+            SeekFrom::Current(offset) => (self.position as u64).checked_add_signed(offset),
+        };
+        match position {
+            Some(position) if position <= ARRAY_SIZE as u64 => {
+                self.position = position as usize;
+                Ok(position)
+            }
+            _ => Err(Error::OutOfBounds),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{Eeprom25aa02, Error, NoDelay, Transport, Variant, E48, PAGE_SIZE};
+use core::ops::Range;
+use embedded_hal::delay::DelayNs;
+
+/// Iterator over the pages of the EEPROM.
+///
+/// Created by [`Eeprom25aa02::pages`].
+///
+/// Each page is read when the iterator is advanced.
+/// The iterator ends after the first error.
+pub struct Pages<'a, SPI, V = E48, D = NoDelay> {
+    pub(crate) eeprom: &'a mut Eeprom25aa02<SPI, V, D>,
+    pub(crate) pages: Range<usize>,
+}
+
+impl<SPI, V, D> Iterator for Pages<'_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    type Item = Result<(u8, [u8; PAGE_SIZE as usize]), Error<SPI::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let address: u8 = (self.pages.next()? * PAGE_SIZE as usize) as u8;
+        match self.eeprom.read_array(address) {
+            Ok(page) => Some(Ok((address, page))),
+            Err(e) => {
+                self.pages = 0..0;
+                Some(Err(e))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pages.size_hint()
+    }
+}
+
+/// Size of the internal buffer used by [`Bytes`].
+const BYTES_CHUNK: usize = 16;
+
+/// Iterator over the bytes of the EEPROM.
+///
+/// Created by [`Eeprom25aa02::bytes`].
+///
+/// Bytes are read in small chunks when the internal buffer runs dry.
+/// The iterator ends after the first error.
+pub struct Bytes<'a, SPI, V = E48, D = NoDelay> {
+    eeprom: &'a mut Eeprom25aa02<SPI, V, D>,
+    range: Range<usize>,
+    buf: [u8; BYTES_CHUNK],
+    buf_pos: usize,
+    buf_len: usize,
+}
+
+impl<'a, SPI, V, D> Bytes<'a, SPI, V, D> {
+    pub(crate) fn new(eeprom: &'a mut Eeprom25aa02<SPI, V, D>, range: Range<usize>) -> Self {
+        Self {
+            eeprom,
+            range,
+            buf: [0; BYTES_CHUNK],
+            buf_pos: 0,
+            buf_len: 0,
+        }
+    }
+}
+
+impl<SPI, V, D> Iterator for Bytes<'_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    type Item = Result<u8, Error<SPI::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf_pos == self.buf_len {
+            if self.range.is_empty() {
+                return None;
+            }
+            let len: usize = self.range.len().min(BYTES_CHUNK);
+            if let Err(e) = self
+                .eeprom
+                .read(self.range.start as u8, &mut self.buf[..len])
+            {
+                self.range = 0..0;
+                self.buf_pos = 0;
+                self.buf_len = 0;
+                return Some(Err(e));
+            }
+            self.range.start += len;
+            self.buf_pos = 0;
+            self.buf_len = len;
+        }
+        let byte: u8 = self.buf[self.buf_pos];
+        self.buf_pos += 1;
+        Some(Ok(byte))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.range.len() + self.buf_len - self.buf_pos;
+        (remaining, Some(remaining))
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    crc::{crc8, crc8_update},
+    Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE,
+};
+use embedded_hal::delay::DelayNs;
+
+/// Marks a journal entry as committed.
+const COMMITTED: u8 = 0xC3;
+
+/// Marks the journal as empty.
+const EMPTY: u8 = 0x00;
+
+/// Journal area for writes that survive power loss.
+///
+/// A journaled write first copies the data into the journal, then sets a
+/// commit marker, then writes the data to its destination, and finally
+/// clears the commit marker.
+/// If power is lost during the write, [`Eeprom25aa02::recover_journal`]
+/// replays committed writes and discards uncommitted ones, so the
+/// destination holds either the old or the new data.
+///
+/// The journal is stored as a commit marker, a CRC-8, the destination
+/// address, the length, and up to `capacity` bytes of data.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # fn write(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+/// #     hal::spi::Transaction::write_vec(data),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #     hal::spi::Transaction::read_vec(vec![0x00]),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # let mut expectations = vec![
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0xA0]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00, 0xFF, 0xFF, 0xFF]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ];
+/// # expectations.extend(write(0xA1, vec![0x92, 0x10, 0x02, 0x12, 0x34]));
+/// # expectations.extend(write(0xA0, vec![0xC3]));
+/// # expectations.extend(write(0x10, vec![0x12, 0x34]));
+/// # expectations.extend(write(0xA0, vec![0x00]));
+/// # let spi = hal::spi::Mock::new(&expectations);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, Journal};
+///
+/// const JOURNAL: Journal = Journal::new(0xA0, 16);
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+///
+/// // on startup
+/// let repaired: bool = eeprom.recover_journal(&JOURNAL)?;
+/// assert!(!repaired);
+///
+/// eeprom.journaled_write(&JOURNAL, 0x10, &[0x12, 0x34])?;
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Journal {
+    address: u8,
+    capacity: usize,
+}
+
+impl Journal {
+    /// Size of the commit marker, CRC, address, and length in bytes.
+    pub const OVERHEAD: usize = 4;
+
+    /// Creates a new journal at an EEPROM memory address, holding writes of
+    /// up to `capacity` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the journal extends past the end of the array, or the
+    /// capacity does not fit in a `u8`.
+    #[inline]
+    pub const fn new(address: u8, capacity: usize) -> Self {
+        assert!(
+            address as usize + capacity + Self::OVERHEAD <= ARRAY_SIZE,
+            "journal extends past the end of the array"
+        );
+        Journal { address, capacity }
+    }
+
+    /// EEPROM memory address of the journal.
+    #[inline]
+    pub const fn address(&self) -> u8 {
+        self.address
+    }
+
+    /// Maximum length of a journaled write in bytes.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Size of the journal in the array in bytes.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.capacity + Self::OVERHEAD
+    }
+
+    fn overlaps(&self, address: u8, len: usize) -> bool {
+        let start: usize = self.address.into();
+        usize::from(address) < start + self.size() && start < usize::from(address) + len
+    }
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Write data through a journal.
+    ///
+    /// This requires four write cycles, and the journal must not overlap the
+    /// destination.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// See [`Journal`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::DataTooLong`] if `data` is longer than the journal
+    ///   capacity.
+    /// * [`Error::OutOfBounds`] if the destination overlaps the journal or
+    ///   extends past the end of the array.
+    pub fn journaled_write(
+        &mut self,
+        journal: &Journal,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        if data.len() > journal.capacity {
+            return Err(Error::DataTooLong);
+        }
+        if usize::from(address) + data.len() > ARRAY_SIZE || journal.overlaps(address, data.len()) {
+            return Err(Error::OutOfBounds);
+        }
+
+        // checked against the capacity, which is less than the array size
+        let header: [u8; 2] = [address, data.len() as u8];
+        let crc: u8 = crc8_update(crc8(&header), data);
+        self.write_parts(journal.address + 1, &[&[crc], &header, data])?;
+        self.write(journal.address, &[COMMITTED])?;
+        self.write(address, data)?;
+        self.write(journal.address, &[EMPTY])
+    }
+
+    /// Recover from a journaled write interrupted by power loss.
+    ///
+    /// This should be called on startup, before the journaled data is read.
+    /// Committed writes are replayed, uncommitted writes are discarded.
+    ///
+    /// Returns `true` if a committed write was replayed.
+    ///
+    /// See [`Journal`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Corrupted`] if the journal is committed but its CRC
+    /// does not match.
+    pub fn recover_journal(&mut self, journal: &Journal) -> Result<bool, Error<SPI::Error>> {
+        let mut header: [u8; Journal::OVERHEAD] = [0; Journal::OVERHEAD];
+        self.read(journal.address, &mut header)?;
+        let [marker, crc, address, len] = header;
+        if marker != COMMITTED {
+            return Ok(false);
+        }
+
+        let len: usize = len.into();
+        if len > journal.capacity || journal.overlaps(address, len) {
+            return Err(Error::Corrupted);
+        }
+        let start: usize = usize::from(journal.address) + Journal::OVERHEAD;
+        let computed: u8 = self.fold_range(start..start + len, crc8(&header[2..]), crc8_update)?;
+        if computed != crc {
+            return Err(Error::Corrupted);
+        }
+
+        self.copy_within(start as u8, address, len)?;
+        self.write(journal.address, &[EMPTY])?;
+        Ok(true)
+    }
+}
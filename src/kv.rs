@@ -0,0 +1,529 @@
+use crate::{
+    crc::crc8, slots::is_newer, EepromRegion, Error, NoDelay, Transport, Variant, ARRAY_SIZE, E48,
+};
+use core::ops::Deref;
+use embedded_hal::delay::DelayNs;
+
+/// Length byte of a deleted entry.
+const TOMBSTONE: u8 = 0xFE;
+
+/// Length byte of erased memory, marking the end of the log.
+const END: u8 = 0xFF;
+
+/// Size of the key, length, and CRC of an entry in bytes.
+const OVERHEAD: usize = 3;
+
+/// Maximum length of a value in bytes.
+const MAX_VALUE_LEN: usize = 32;
+
+/// Size of the generation and CRC at the start of a bank in bytes.
+const HEADER_LEN: usize = 2;
+
+/// Maximum number of entries in a bank.
+const MAX_ENTRIES: usize = (ARRAY_SIZE / 2 - HEADER_LEN) / OVERHEAD;
+
+/// Key-value store in a region of the EEPROM.
+///
+/// The region is split into two banks, each starting with a generation
+/// number and its CRC-8.
+/// The bank with the newest valid generation is active, and entries are
+/// appended to its log as a key, a length, the value, and a CRC-8.
+/// An entry torn by power loss fails its CRC and is treated as the end of
+/// the log, so the previous value of the key is kept.
+///
+/// When the active bank is full the latest value of each key is copied to
+/// the other bank, and the generation of the other bank is written last.
+/// Power loss during compaction leaves the old bank active, and no entries
+/// are lost.
+///
+/// The region must be filled with `0xFF` before first use, see
+/// [`KvStore::clear`].
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # fn read(address: u8, len: usize, data: &[u8]) -> Vec<hal::spi::Transaction<u8>> {
+/// #   let mut data = data.to_vec();
+/// #   data.resize(len, 0xFF);
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+/// #     hal::spi::Transaction::read_vec(data),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # let entry = [0x01, 0x02, 0x12, 0x34, 0x2A];
+/// # let mut expectations = vec![];
+/// # expectations.extend(read(0x20, 2, &[]));
+/// # expectations.extend(read(0x30, 2, &[]));
+/// # expectations.extend(read(0x22, 14, &[]));
+/// # expectations.extend([
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x22]),
+/// #   hal::spi::Transaction::write_vec(entry.to_vec()),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// # for _ in 0..2 {
+/// #   expectations.extend(read(0x20, 2, &[]));
+/// #   expectations.extend(read(0x30, 2, &[]));
+/// #   expectations.extend(read(0x22, 14, &entry));
+/// #   expectations.extend(read(0x27, 9, &[]));
+/// # }
+/// # expectations.extend(read(0x22, 14, &entry));
+/// # let spi = hal::spi::Mock::new(&expectations);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, KvStore};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// let mut store = KvStore::new(eeprom.region(0x20, 32)?);
+///
+/// store.put(1, &[0x12, 0x34])?;
+///
+/// let mut buf: [u8; 8] = [0; 8];
+/// assert_eq!(store.get(1, &mut buf)?, Some(2));
+/// assert_eq!(buf[..2], [0x12, 0x34]);
+///
+/// for entry in store.iter()? {
+///     let (key, value) = entry?;
+///     assert_eq!(key, 1);
+///     assert_eq!(*value, [0x12, 0x34]);
+/// }
+/// # drop(store);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct KvStore<'a, SPI, V = E48, D = NoDelay> {
+    region: EepromRegion<'a, SPI, V, D>,
+}
+
+/// Bank of the region holding a log.
+#[derive(Clone, Copy)]
+struct Bank {
+    /// Offset of the bank in the region.
+    start: usize,
+    generation: u8,
+}
+
+/// Log entry read from the EEPROM.
+#[derive(Clone, Copy)]
+struct Entry {
+    key: u8,
+    /// `None` for deleted entries.
+    value: Option<KvValue>,
+}
+
+impl Entry {
+    fn len(&self) -> usize {
+        OVERHEAD + self.value.map_or(0, |value| value.len())
+    }
+}
+
+/// Offsets and keys of the newest entry of every key that has not been
+/// deleted, oldest first.
+struct Live {
+    /// Offsets relative to the start of the bank.
+    offsets: [u8; MAX_ENTRIES],
+    keys: [u8; MAX_ENTRIES],
+    len: usize,
+}
+
+/// Serialize an entry into `buf`, returning the length.
+fn encode(buf: &mut [u8], key: u8, value: Option<&[u8]>) -> usize {
+    let value_len: usize = value.map_or(0, <[u8]>::len);
+    buf[0] = key;
+    // checked against MAX_VALUE_LEN
+    buf[1] = value.map_or(TOMBSTONE, |value| value.len() as u8);
+    if let Some(value) = value {
+        buf[2..2 + value_len].copy_from_slice(value);
+    }
+    buf[2 + value_len] = crc8(&buf[..2 + value_len]);
+    OVERHEAD + value_len
+}
+
+impl<SPI, V, D> KvStore<'_, SPI, V, D> {
+    /// Maximum length of a value in bytes.
+    pub const MAX_VALUE_LEN: usize = MAX_VALUE_LEN;
+}
+
+impl<'a, SPI, V, D> KvStore<'a, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Creates a key-value store in a region.
+    #[inline]
+    pub fn new(region: EepromRegion<'a, SPI, V, D>) -> Self {
+        KvStore { region }
+    }
+
+    /// Free the underlying region.
+    #[inline]
+    pub fn free(self) -> EepromRegion<'a, SPI, V, D> {
+        self.region
+    }
+
+    /// Length of a bank in bytes.
+    fn bank_len(&self) -> usize {
+        self.region.len() / 2
+    }
+
+    /// Find the bank with the newest valid generation.
+    ///
+    /// The first bank is active with generation 0 if neither is valid.
+    fn active(&mut self) -> Result<Bank, Error<SPI::Error>> {
+        let mut active: Option<Bank> = None;
+        for start in [0, self.bank_len()] {
+            let mut header: [u8; HEADER_LEN] = [0; HEADER_LEN];
+            self.region.read(start, &mut header)?;
+            let generation: u8 = header[0];
+            if crc8(&header[..1]) == header[1]
+                && active.is_none_or(|bank| is_newer(generation, bank.generation))
+            {
+                active = Some(Bank { start, generation });
+            }
+        }
+        Ok(active.unwrap_or(Bank {
+            start: 0,
+            generation: 0,
+        }))
+    }
+
+    /// Read the entry at `offset` in the region.
+    ///
+    /// Returns `None` at the end of the log.
+    fn entry(&mut self, bank: Bank, offset: usize) -> Result<Option<Entry>, Error<SPI::Error>> {
+        let mut buf: [u8; OVERHEAD + MAX_VALUE_LEN] = [0; OVERHEAD + MAX_VALUE_LEN];
+        let len: usize = (bank.start + self.bank_len() - offset).min(buf.len());
+        if len < OVERHEAD {
+            return Ok(None);
+        }
+        let buf: &mut [u8] = &mut buf[..len];
+        self.region.read(offset, buf)?;
+
+        let (key, len) = (buf[0], buf[1]);
+        let value_len: usize = match len {
+            END => return Ok(None),
+            TOMBSTONE => 0,
+            len if usize::from(len) <= MAX_VALUE_LEN => len.into(),
+            _ => return Ok(None),
+        };
+        let Some(entry) = buf.get(..OVERHEAD + value_len) else {
+            return Ok(None);
+        };
+        let (data, crc) = entry.split_at(entry.len() - 1);
+        if crc8(data) != crc[0] {
+            return Ok(None);
+        }
+        Ok(Some(Entry {
+            key,
+            value: (len != TOMBSTONE).then(|| KvValue::new(&data[2..])),
+        }))
+    }
+
+    /// Call `f` with the offset of every valid entry in a bank, returning
+    /// the offset of the end of the log.
+    fn scan(
+        &mut self,
+        bank: Bank,
+        mut f: impl FnMut(usize, &Entry),
+    ) -> Result<usize, Error<SPI::Error>> {
+        let mut offset: usize = bank.start + HEADER_LEN;
+        while let Some(entry) = self.entry(bank, offset)? {
+            f(offset, &entry);
+            offset += entry.len();
+        }
+        Ok(offset)
+    }
+
+    /// Find the newest entry of every key that has not been deleted.
+    fn live(&mut self, bank: Bank) -> Result<Live, Error<SPI::Error>> {
+        let mut offsets: [u8; MAX_ENTRIES] = [0; MAX_ENTRIES];
+        let mut keys: [u8; MAX_ENTRIES] = [0; MAX_ENTRIES];
+        let mut deleted: u64 = 0;
+        let mut count: usize = 0;
+        self.scan(bank, |offset, entry| {
+            // a bank is at most half of the array
+            offsets[count] = (offset - bank.start) as u8;
+            keys[count] = entry.key;
+            if entry.value.is_none() {
+                deleted |= 1 << count;
+            }
+            count += 1;
+        })?;
+
+        // the last entry of a key is the newest
+        let mut seen: [u32; 8] = [0; 8];
+        let mut live: Live = Live {
+            offsets: [0; MAX_ENTRIES],
+            keys: [0; MAX_ENTRIES],
+            len: 0,
+        };
+        for n in (0..count).rev() {
+            let word: &mut u32 = &mut seen[usize::from(keys[n] / 32)];
+            let bit: u32 = 1 << (keys[n] % 32);
+            if *word & bit == 0 {
+                *word |= bit;
+                if deleted & (1 << n) == 0 {
+                    live.offsets[live.len] = offsets[n];
+                    live.keys[live.len] = keys[n];
+                    live.len += 1;
+                }
+            }
+        }
+        live.offsets[..live.len].reverse();
+        live.keys[..live.len].reverse();
+        Ok(live)
+    }
+
+    /// Erase every entry, filling the region with `0xFF`.
+    pub fn clear(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.region.fill(0xFF)
+    }
+
+    /// Get the value of a key.
+    ///
+    /// Returns the length of the value copied into `buf`, or `None` if the
+    /// key does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if `buf` is shorter than the value.
+    pub fn get(&mut self, key: u8, buf: &mut [u8]) -> Result<Option<usize>, Error<SPI::Error>> {
+        let bank: Bank = self.active()?;
+        let mut latest: Option<Entry> = None;
+        self.scan(bank, |_, entry| {
+            if entry.key == key {
+                latest = Some(*entry);
+            }
+        })?;
+        match latest.and_then(|entry| entry.value) {
+            Some(value) => {
+                let dst: &mut [u8] = buf.get_mut(..value.len()).ok_or(Error::DataTooLong)?;
+                dst.copy_from_slice(&value);
+                Ok(Some(value.len()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Append an entry, compacting the log if the bank is full.
+    fn append(&mut self, key: u8, value: Option<&[u8]>) -> Result<(), Error<SPI::Error>> {
+        let bank: Bank = self.active()?;
+        let end: usize = self.scan(bank, |_, _| ())?;
+        let mut entry: [u8; OVERHEAD + MAX_VALUE_LEN] = [0; OVERHEAD + MAX_VALUE_LEN];
+        let len: usize = encode(&mut entry, key, value);
+        if end + len <= bank.start + self.bank_len() {
+            return self.region.write(end, &entry[..len]);
+        }
+
+        let live: Live = self.live(bank)?;
+        let bank_len: usize = self.bank_len();
+        let target: usize = if bank.start == 0 { bank_len } else { 0 };
+        self.region.fill_range(target, bank_len, 0xFF)?;
+
+        let mut offset: usize = target + HEADER_LEN;
+        for (&src, _) in live.offsets[..live.len]
+            .iter()
+            .zip(live.keys[..live.len].iter())
+            .filter(|(_, &k)| k != key)
+        {
+            let copy: Entry = self
+                .entry(bank, bank.start + usize::from(src))?
+                .ok_or(Error::Corrupted)?;
+            let mut buf: [u8; OVERHEAD + MAX_VALUE_LEN] = [0; OVERHEAD + MAX_VALUE_LEN];
+            let len: usize = encode(&mut buf, copy.key, copy.value.as_deref());
+            self.region.write(offset, &buf[..len])?;
+            offset += len;
+        }
+        // a deleted key is dropped by compaction
+        if value.is_some() {
+            if offset + len > target + bank_len {
+                return Err(Error::DataTooLong);
+            }
+            self.region.write(offset, &entry[..len])?;
+        }
+
+        let generation: u8 = bank.generation.wrapping_add(1);
+        self.region
+            .write(target, &[generation, crc8(&[generation])])
+    }
+
+    /// Set the value of a key.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if the value is longer than
+    /// [`KvStore::MAX_VALUE_LEN`], or does not fit in a bank after
+    /// compaction.
+    ///
+    /// # Example
+    ///
+    /// Compaction copies the latest values to the other bank, then writes
+    /// the generation of the other bank.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # fn read(address: u8, len: usize, data: &[u8]) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   let mut data = data.to_vec();
+    /// #   data.resize(len, 0xFF);
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+    /// #     hal::spi::Transaction::read_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # fn write(address: u8, data: &[u8]) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(data.to_vec()),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # let mut expectations = vec![];
+    /// # expectations.extend(read(0x20, 2, &[]));
+    /// # expectations.extend(read(0x28, 2, &[]));
+    /// # expectations.extend(read(0x22, 6, &[0x01, 0x02, 0x12, 0x34, 0x2A]));
+    /// # expectations.extend(read(0x22, 6, &[0x01, 0x02, 0x12, 0x34, 0x2A]));
+    /// # expectations.extend(write(0x28, &[0xFF; 8]));
+    /// # expectations.extend(write(0x2A, &[0x01, 0x02, 0x56, 0x78, 0x62]));
+    /// # expectations.extend(write(0x28, &[0x01, 0xD9]));
+    /// # expectations.extend(read(0x20, 2, &[]));
+    /// # expectations.extend(read(0x28, 2, &[0x01, 0xD9]));
+    /// # expectations.extend(read(0x2A, 6, &[0x01, 0x02, 0x56, 0x78, 0x62]));
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, KvStore};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let mut store = KvStore::new(eeprom.region(0x20, 16)?);
+    ///
+    /// // the first bank holds one entry, and is full
+    /// store.put(1, &[0x56, 0x78])?;
+    ///
+    /// let mut buf: [u8; 8] = [0; 8];
+    /// assert_eq!(store.get(1, &mut buf)?, Some(2));
+    /// assert_eq!(buf[..2], [0x56, 0x78]);
+    /// # drop(store);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn put(&mut self, key: u8, value: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if value.len() > Self::MAX_VALUE_LEN {
+            return Err(Error::DataTooLong);
+        }
+        self.append(key, Some(value))
+    }
+
+    /// Delete a key.
+    ///
+    /// Returns `true` if the key existed.
+    pub fn delete(&mut self, key: u8) -> Result<bool, Error<SPI::Error>> {
+        let bank: Bank = self.active()?;
+        let mut exists: bool = false;
+        self.scan(bank, |_, entry| {
+            if entry.key == key {
+                exists = entry.value.is_some();
+            }
+        })?;
+        if exists {
+            self.append(key, None)?;
+        }
+        Ok(exists)
+    }
+
+    /// Returns an iterator over the keys and values.
+    ///
+    /// The keys are found when the iterator is created, and each value is
+    /// read as the iterator advances.
+    pub fn iter(&mut self) -> Result<KvIter<'_, 'a, SPI, V, D>, Error<SPI::Error>> {
+        let bank: Bank = self.active()?;
+        let live: Live = self.live(bank)?;
+        Ok(KvIter {
+            store: self,
+            bank,
+            live,
+            index: 0,
+        })
+    }
+}
+
+/// Iterator over the keys and values of a [`KvStore`].
+///
+/// Created by [`KvStore::iter`].
+pub struct KvIter<'s, 'a, SPI, V = E48, D = NoDelay> {
+    store: &'s mut KvStore<'a, SPI, V, D>,
+    bank: Bank,
+    live: Live,
+    index: usize,
+}
+
+impl<SPI, V, D> Iterator for KvIter<'_, '_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    type Item = Result<(u8, KvValue), Error<SPI::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset: u8 = *self.live.offsets[..self.live.len].get(self.index)?;
+        self.index += 1;
+        let offset: usize = self.bank.start + usize::from(offset);
+        Some(match self.store.entry(self.bank, offset) {
+            Ok(Some(Entry {
+                key,
+                value: Some(value),
+            })) => Ok((key, value)),
+            Ok(_) => Err(Error::Corrupted),
+            Err(e) => Err(e),
+        })
+    }
+}
+
+/// Value of a [`KvStore`] entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KvValue {
+    data: [u8; MAX_VALUE_LEN],
+    len: u8,
+}
+
+impl KvValue {
+    fn new(value: &[u8]) -> Self {
+        let mut data: [u8; MAX_VALUE_LEN] = [0; MAX_VALUE_LEN];
+        data[..value.len()].copy_from_slice(value);
+        KvValue {
+            data,
+            // at most MAX_VALUE_LEN
+            len: value.len() as u8,
+        }
+    }
+}
+
+impl Deref for KvValue {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.data[..usize::from(self.len)]
+    }
+}
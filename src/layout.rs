@@ -0,0 +1,240 @@
+use crate::{Eeprom25aa02, Error, Transport, Variant};
+use core::marker::PhantomData;
+use embedded_hal::delay::DelayNs;
+
+/// Value that can be stored in a [`Field`].
+///
+/// Integers and floats are stored little endian.
+pub trait FieldValue: Sized {
+    /// Serialized representation.
+    type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default;
+    /// Number of bytes in the serialized representation.
+    const SIZE: usize;
+
+    /// Serialize the value.
+    fn to_bytes(&self) -> Self::Bytes;
+
+    /// Deserialize the value.
+    fn from_bytes(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! impl_field_value {
+    ($($ty:ty),+) => {
+        $(
+            impl FieldValue for $ty {
+                type Bytes = [u8; core::mem::size_of::<$ty>()];
+                const SIZE: usize = core::mem::size_of::<$ty>();
+
+                #[inline]
+                fn to_bytes(&self) -> Self::Bytes {
+                    self.to_le_bytes()
+                }
+
+                #[inline]
+                fn from_bytes(bytes: Self::Bytes) -> Self {
+                    <$ty>::from_le_bytes(bytes)
+                }
+            }
+        )+
+    };
+}
+
+impl_field_value!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl FieldValue for bool {
+    type Bytes = [u8; 1];
+    const SIZE: usize = 1;
+
+    #[inline]
+    fn to_bytes(&self) -> Self::Bytes {
+        [u8::from(*self)]
+    }
+
+    #[inline]
+    fn from_bytes(bytes: Self::Bytes) -> Self {
+        bytes[0] != 0
+    }
+}
+
+impl<const N: usize> FieldValue for [u8; N]
+where
+    [u8; N]: Default,
+{
+    type Bytes = [u8; N];
+    const SIZE: usize = N;
+
+    #[inline]
+    fn to_bytes(&self) -> Self::Bytes {
+        *self
+    }
+
+    #[inline]
+    fn from_bytes(bytes: Self::Bytes) -> Self {
+        bytes
+    }
+}
+
+/// Typed field at a fixed EEPROM address.
+///
+/// Fields are usually defined with [`eeprom_layout!`](crate::eeprom_layout),
+/// and accessed with [`Eeprom25aa02::read_field`] and
+/// [`Eeprom25aa02::write_field`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Field<T> {
+    address: u8,
+    value: PhantomData<T>,
+}
+
+impl<T: FieldValue> Field<T> {
+    /// Creates a new field at an EEPROM memory address.
+    #[inline]
+    pub const fn new(address: u8) -> Self {
+        Field {
+            address,
+            value: PhantomData,
+        }
+    }
+
+    /// EEPROM memory address of the field.
+    #[inline]
+    pub const fn address(&self) -> u8 {
+        self.address
+    }
+
+    /// Size of the field in bytes.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        T::SIZE
+    }
+}
+
+/// Define a layout of typed fields at fixed EEPROM addresses.
+///
+/// Each field becomes an associated [`Field`] constant of the layout type.
+/// Layouts are checked at compile time, fields may not overlap or extend
+/// past the end of the array.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::read_vec(vec![0x2A, 0x00, 0x00, 0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x00]),
+/// #   hal::spi::Transaction::write_vec(vec![0x2B, 0x00, 0x00, 0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{eeprom_layout, Eeprom25aa02e48};
+///
+/// eeprom_layout! {
+///     /// Device settings.
+///     pub struct Settings {
+///         /// Number of boots.
+///         BOOT_COUNT: u32 = 0x00,
+///         BRIGHTNESS: u8 = 0x04,
+///         NAME: [u8; 8] = 0x10,
+///     }
+/// }
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// let boot_count: u32 = eeprom.read_field(Settings::BOOT_COUNT)?;
+/// eeprom.write_field(Settings::BOOT_COUNT, &(boot_count + 1))?;
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+///
+/// Overlapping fields do not compile.
+///
+/// ```compile_fail
+/// use eeprom25aa02e48::eeprom_layout;
+///
+/// eeprom_layout! {
+///     struct Settings {
+///         BOOT_COUNT: u32 = 0x00,
+///         BRIGHTNESS: u8 = 0x03,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! eeprom_layout {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $ty:ty = $address:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name;
+
+        impl $name {
+            $(
+                $(#[$field_meta])*
+                $vis const $field: $crate::Field<$ty> = $crate::Field::new($address);
+            )*
+        }
+
+        const _: () = {
+            let fields: &[(usize, usize)] = &[
+                $(($address as usize, <$ty as $crate::FieldValue>::SIZE)),*
+            ];
+            let mut i: usize = 0;
+            while i < fields.len() {
+                assert!(fields[i].0 + fields[i].1 <= 256, "field extends past the end of the array");
+                let mut j: usize = i + 1;
+                while j < fields.len() {
+                    assert!(
+                        fields[i].0 + fields[i].1 <= fields[j].0
+                            || fields[j].0 + fields[j].1 <= fields[i].0,
+                        "fields overlap"
+                    );
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
+    };
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Read a typed field.
+    ///
+    /// See [`eeprom_layout!`](crate::eeprom_layout) for an example.
+    pub fn read_field<T: FieldValue>(&mut self, field: Field<T>) -> Result<T, Error<SPI::Error>> {
+        let mut bytes: T::Bytes = T::Bytes::default();
+        self.read(field.address, bytes.as_mut())?;
+        Ok(T::from_bytes(bytes))
+    }
+
+    /// Write a typed field.
+    ///
+    /// Waits for the write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// See [`eeprom_layout!`](crate::eeprom_layout) for an example.
+    pub fn write_field<T: FieldValue>(
+        &mut self,
+        field: Field<T>,
+        value: &T,
+    ) -> Result<(), Error<SPI::Error>> {
+        self.write(field.address, value.to_bytes().as_ref())
+    }
+}
@@ -13,18 +13,35 @@
 //! # let spi = hal::spi::Mock::new(&[
 //! #   hal::spi::Transaction::transaction_start(),
 //! #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
-//! #   hal::spi::Transaction::transfer_in_place(vec![0; 6], vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
+//! #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
 //! #   hal::spi::Transaction::transaction_end(),
 //! # ]);
-//! use eeprom25aa02e48::Eeprom25aa02e48;
+//! use eeprom25aa02e48::{Eeprom25aa02e48, Eui48};
 //!
 //! let mut eeprom = Eeprom25aa02e48::new(spi);
-//! let eui48: [u8; 6] = eeprom.read_eui48()?;
-//! # assert_eq!(eui48, [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
+//! let eui48: Eui48 = eeprom.read_eui48()?;
+//! # assert_eq!(eui48, Eui48([0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]));
 //! # let mut spi = eeprom.free(); spi.done();
-//! # Ok::<(), embedded_hal::spi::ErrorKind>(())
+//! # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
 //! ```
 //!
+//! # Write cycles
+//!
+//! Writing to the array or the STATUS register starts an internal write
+//! cycle of up to [`WRITE_CYCLE_TIME_MS`], during which the EEPROM ignores
+//! further writes.
+//! A write cannot cross a page boundary, so data spanning pages is split
+//! into one write, and one write cycle, per page.
+//!
+//! Methods that write more than one page, or read the data back, wait for
+//! each write cycle by polling the write-in-progress bit of the STATUS
+//! register, see [`Eeprom25aa02::wait_write_complete`].
+//! Single page writes return as soon as the write cycle has started, unless
+//! the driver was created with [`Eeprom25aa02::new_with_delay`], which waits
+//! for every write cycle and paces the polls with the delay.
+//! Polling gives up with [`Error::Timeout`] if a write cycle does not
+//! complete, for example when the device is missing.
+//!
 //! [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
 //! [eeprom24x-rs]: https://github.com/eldruin/eeprom24x-rs
 //! [Microchip 25AA02E48]: http://ww1.microchip.com/downloads/en/DeviceDoc/25AA02E48-25AA02E64-2K-SPI-Bus-Serial-EEPROM-Data%20Sheet_DS20002123G.pdf
@@ -32,7 +49,123 @@
 #![warn(missing_docs)]
 #![no_std]
 
-use embedded_hal::spi::Operation;
+use bus::Bus;
+use core::{
+    fmt,
+    marker::PhantomData,
+    num::{NonZeroU8, NonZeroUsize},
+    ops::{Bound, Range, RangeBounds},
+};
+use embedded_hal::{delay::DelayNs, spi::Operation};
+#[cfg(feature = "embedded-hal-bus")]
+use embedded_hal::{digital::OutputPin, spi::SpiBus};
+#[cfg(feature = "embedded-hal-bus")]
+use embedded_hal_bus::spi::ExclusiveDevice;
+
+mod address;
+#[cfg(feature = "hmac")]
+mod authenticated;
+mod bus;
+mod cache;
+#[cfg(feature = "serde")]
+mod codec;
+pub mod commands;
+#[cfg(feature = "serde")]
+mod config;
+mod counter;
+mod crc;
+mod ecc;
+pub mod eeprom24x;
+#[cfg(feature = "eh0")]
+pub mod eh0;
+#[cfg(feature = "chacha20poly1305")]
+mod encrypted;
+mod erase;
+mod eui48;
+mod family;
+#[cfg(feature = "bus-health")]
+mod health;
+#[cfg(feature = "embedded-io")]
+mod io;
+mod iter;
+mod journal;
+mod kv;
+mod layout;
+pub mod ll;
+pub mod memory_map;
+mod partition;
+#[cfg(feature = "zerocopy")]
+mod pod;
+mod policy;
+mod record;
+mod record_log;
+mod region;
+mod ring;
+mod scrub;
+mod self_test;
+mod shadow;
+#[cfg(feature = "critical-section")]
+mod shared;
+mod slots;
+mod status;
+#[cfg(feature = "embedded-storage")]
+mod storage;
+#[cfg(feature = "serde")]
+mod store;
+mod tmr;
+mod transaction;
+mod transport;
+pub mod typestate;
+#[cfg(feature = "uart-bridge")]
+pub mod uart;
+mod variant;
+mod wear;
+mod wp;
+
+pub use address::{Address, PageIndex};
+pub use cache::CachedEeprom;
+#[cfg(feature = "cbor")]
+pub use codec::Cbor;
+#[cfg(feature = "postcard")]
+pub use codec::Postcard;
+#[cfg(feature = "serde")]
+pub use codec::{Codec, CodecError};
+#[cfg(feature = "serde")]
+pub use config::{Migration, VersionedConfig};
+pub use counter::PersistentCounter;
+#[cfg(feature = "derive")]
+pub use eeprom25aa02e48_derive::EepromRecord;
+pub use eui48::{Eui48, ParseEui48Error, Separator, MICROCHIP_OUIS};
+pub use family::{
+    Eeprom25aa040a, Eeprom25aa080a, Eeprom25aa080b, Eeprom25aa160a, Eeprom25aa160b, Eeprom25aa320a,
+    Eeprom25x,
+};
+#[cfg(feature = "bus-health")]
+pub use health::BusHealth;
+#[cfg(feature = "embedded-io")]
+pub use io::EepromCursor;
+pub use iter::{Bytes, Pages};
+pub use journal::Journal;
+pub use kv::{KvIter, KvStore, KvValue};
+pub use layout::{Field, FieldValue};
+pub use partition::{Partition, PartitionTable, PARTITION_TABLE_ADDRESS};
+pub use policy::{AllowAll, DefaultWritePolicy, WritePolicy};
+pub use record::EepromRecord;
+pub use record_log::{RecordLog, Records};
+pub use region::EepromRegion;
+pub use ring::{RingEntries, RingLog};
+pub use scrub::ScrubStats;
+pub use shadow::ShadowEeprom;
+#[cfg(feature = "critical-section")]
+pub use shared::SharedEeprom;
+pub use slots::AbSlots;
+pub use status::{BlockProtect, StatusRegister};
+pub use tmr::TripleSlots;
+pub use transaction::Transaction;
+pub use transport::Transport;
+pub use variant::{Variant, E48, E64};
+pub use wear::WearLeveled;
+pub use wp::{WpError, WriteProtected};
 
 /// EEPROM instructions.
 pub mod instruction {
@@ -54,18 +187,297 @@ pub mod instruction {
 pub const EUI48_BYTES: usize = 6;
 /// EPPROM memory address of the EUI48 address.
 pub const EUI48_MEMORY_ADDRESS: u8 = 0xFA;
+/// Number of bytes in an EUI-64 node address.
+pub const EUI64_BYTES: usize = 8;
+/// EEPROM memory address of the EUI-64 node address on the 25AA02E64.
+pub const EUI64_MEMORY_ADDRESS: u8 = 0xF8;
 /// EEPROM page size in bytes.
 pub const PAGE_SIZE: u8 = 16;
+/// EEPROM memory address of the upper quarter of the array.
+///
+/// This block contains the EUI-48 and is write-protected from the factory.
+pub const PROTECTED_BLOCK_ADDRESS: u8 = 0xC0;
+/// Maximum internal write cycle time in milliseconds.
+pub const WRITE_CYCLE_TIME_MS: u32 = 5;
+/// Bus turnaround time in nanoseconds between the end of a command and the
+/// start of a read in half-duplex mode.
+///
+/// This is the maximum output valid from clock low time (T<sub>V</sub>) at
+/// the lowest supply voltage, the time the EEPROM takes to drive the first
+/// data bit onto SO after the last address bit.
+/// See [`Eeprom25aa02e48::new_half_duplex`].
+pub const HALF_DUPLEX_TURNAROUND_NS: u32 = 160;
 
-/// Microchip 25AA02E48 driver.
+/// Eeprom25aa02e48 error type.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::Error;
+/// use embedded_hal::spi::ErrorKind;
+///
+/// let e: Error<ErrorKind> = Error::VerifyFailed { offset: 3 };
+/// assert_eq!(e.to_string(), "verification failed at offset 3");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error<E> {
+    /// SPI bus error.
+    Spi(E),
+    /// The device did not respond to a probe.
+    ///
+    /// This occurs when the STATUS register reads back with the write enable
+    /// latch set after a write disable instruction, for example when MISO is
+    /// floating or pulled high.
+    NoDevice,
+    /// The automatic recovery policy recovered the device after too many
+    /// consecutive errors.
+    ///
+    /// The requested operation was **not** performed, and may be retried.
+    Recovered,
+    /// The automatic recovery policy failed to recover the device.
+    ///
+    /// The requested operation was **not** performed.
+    Unrecoverable,
+    /// Two reads of the EUI-48 MAC address returned different values.
+    Eui48Mismatch,
+    /// The EUI-48 MAC address is blank, all `0x00` or all `0xFF`.
+    BlankEui48,
+    /// Data read back after a write did not match the data written.
+    VerifyFailed {
+        /// Offset of the first mismatching byte from the start of the write.
+        offset: usize,
+    },
+    /// The access is outside of the addressable range.
+    OutOfBounds,
+    /// The data or buffer is too long for the operation.
+    DataTooLong,
+    /// The address is not page aligned.
+    NotPageAligned,
+    /// The write was denied by the [`WritePolicy`].
+    WriteDenied,
+    /// The write overlaps the write-protected upper quarter of the array,
+    /// starting at [`PROTECTED_BLOCK_ADDRESS`].
+    ///
+    /// The EEPROM silently ignores these writes.
+    ProtectedRegion,
+    /// Data read from the EEPROM failed an integrity check.
+    Corrupted,
+    /// The partition overlaps another partition, or reuses its ID.
+    PartitionConflict,
+    /// The partition does not exist.
+    PartitionNotFound,
+    /// The value could not be serialized.
+    Serialization,
+    /// The stored data has a newer schema version than the firmware
+    /// supports.
+    UnsupportedVersion,
+    /// Data read from the EEPROM failed authentication, the key is
+    /// incorrect or the data was modified.
+    AuthenticationFailed,
+    /// The write cycle did not complete in time.
+    ///
+    /// This occurs when the STATUS register always reads back with the
+    /// write-in-progress bit set, for example when MISO is floating or
+    /// pulled high.
+    Timeout,
+}
+
+impl<E> From<E> for Error<E> {
+    #[inline]
+    fn from(e: E) -> Self {
+        Error::Spi(e)
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Spi(e) => write!(f, "SPI bus error: {e:?}"),
+            Error::NoDevice => f.write_str("no device responded"),
+            Error::Recovered => f.write_str("device recovered, operation not performed"),
+            Error::Unrecoverable => f.write_str("device recovery failed"),
+            Error::Eui48Mismatch => f.write_str("EUI-48 reads did not match"),
+            Error::BlankEui48 => f.write_str("EUI-48 is blank"),
+            Error::VerifyFailed { offset } => {
+                write!(f, "verification failed at offset {offset}")
+            }
+            Error::OutOfBounds => f.write_str("access out of bounds"),
+            Error::DataTooLong => f.write_str("data too long"),
+            Error::NotPageAligned => f.write_str("address not page aligned"),
+            Error::WriteDenied => f.write_str("write denied by policy"),
+            Error::ProtectedRegion => f.write_str("write to protected region"),
+            Error::Corrupted => f.write_str("data corrupted"),
+            Error::PartitionConflict => f.write_str("partition conflict"),
+            Error::PartitionNotFound => f.write_str("partition not found"),
+            Error::Serialization => f.write_str("serialization failed"),
+            Error::UnsupportedVersion => f.write_str("unsupported version"),
+            Error::AuthenticationFailed => f.write_str("authentication failed"),
+            Error::Timeout => f.write_str("write cycle timed out"),
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for Error<E> {}
+
+/// Forwards the kind of SPI bus errors, all other errors are
+/// [`ErrorKind::Other`](embedded_hal::spi::ErrorKind::Other).
+///
+/// This allows the driver error to be used where an SPI error is expected.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::Error;
+/// use embedded_hal::spi::{Error as _, ErrorKind};
+///
+/// let e: Error<ErrorKind> = Error::Spi(ErrorKind::ModeFault);
+/// assert_eq!(e.kind(), ErrorKind::ModeFault);
+/// assert_eq!(Error::<ErrorKind>::NoDevice.kind(), ErrorKind::Other);
+/// ```
+impl<E: embedded_hal::spi::Error> embedded_hal::spi::Error for Error<E> {
+    fn kind(&self) -> embedded_hal::spi::ErrorKind {
+        match self {
+            Error::Spi(e) => e.kind(),
+            _ => embedded_hal::spi::ErrorKind::Other,
+        }
+    }
+}
+
+/// Error returned by [`Eeprom25aa02e48::clone_into`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CloneError<E1, E2> {
+    /// Error from the source device.
+    Source(Error<E1>),
+    /// Error from the destination device.
+    ///
+    /// [`Error::VerifyFailed`] offsets are relative to the start of the
+    /// cloned range.
+    Destination(Error<E2>),
+}
+
+impl<E1: fmt::Debug, E2: fmt::Debug> fmt::Display for CloneError<E1, E2> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CloneError::Source(e) => write!(f, "source: {e}"),
+            CloneError::Destination(e) => write!(f, "destination: {e}"),
+        }
+    }
+}
+
+impl<E1: fmt::Debug, E2: fmt::Debug> core::error::Error for CloneError<E1, E2> {}
+
+/// Outcome of a majority-voted read.
+///
+/// Returned by [`Eeprom25aa02e48::read_robust`] and
+/// [`Eeprom25aa02e48::read_triple`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Vote {
+    /// The first two reads were identical.
+    ///
+    /// For [`Eeprom25aa02e48::read_triple`], every slot was identical.
+    Unanimous,
+    /// The first two reads disagreed, a third read was used to correct the
+    /// data with a bitwise majority vote.
+    ///
+    /// For [`Eeprom25aa02e48::read_triple`], the three slots disagreed and
+    /// the outlier was rewritten.
+    Corrected,
+}
+
+/// EEPROM array size in bytes.
+pub(crate) const ARRAY_SIZE: usize = 256;
+
+/// Convert a range of EEPROM addresses to a range of array indices.
+fn address_range(range: impl RangeBounds<u8>) -> Range<usize> {
+    let start: usize = match range.start_bound() {
+        Bound::Included(&start) => start.into(),
+        Bound::Excluded(&start) => usize::from(start) + 1,
+        Bound::Unbounded => 0,
+    };
+    let end: usize = match range.end_bound() {
+        Bound::Included(&end) => usize::from(end) + 1,
+        Bound::Excluded(&end) => end.into(),
+        Bound::Unbounded => ARRAY_SIZE,
+    };
+    start..end.max(start)
+}
+
+/// Iterator over chunks of a range of array indices that do not cross a page
+/// boundary.
+struct PageChunks(Range<usize>);
+
+impl Iterator for PageChunks {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            None
+        } else {
+            let start: usize = self.0.start;
+            let end: usize =
+                (start - start % PAGE_SIZE as usize + PAGE_SIZE as usize).min(self.0.end);
+            self.0.start = end;
+            Some(start..end)
+        }
+    }
+}
+
+impl DoubleEndedIterator for PageChunks {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            None
+        } else {
+            let end: usize = self.0.end;
+            let start: usize = ((end - 1) - (end - 1) % PAGE_SIZE as usize).max(self.0.start);
+            self.0.end = start;
+            Some(start..end)
+        }
+    }
+}
+
+/// Split a range of array indices into chunks that do not cross a page
+/// boundary.
+fn page_chunks(range: Range<usize>) -> PageChunks {
+    PageChunks(range)
+}
+
+/// Placeholder delay type for drivers created without a delay.
+///
+/// See [`Eeprom25aa02e48::new_with_delay`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoDelay;
+
+impl DelayNs for NoDelay {
+    #[inline]
+    fn delay_ns(&mut self, _ns: u32) {}
+}
+
+/// Microchip 25AA02 family driver.
+///
+/// The variant `V` selects the node address programmed into the chip, see
+/// [`Eeprom25aa02e48`] and [`Eeprom25aa02e64`].
 #[derive(Default)]
-pub struct Eeprom25aa02e48<SPI> {
-    spi: SPI,
+pub struct Eeprom25aa02<SPI, V = E48, D = NoDelay> {
+    bus: Bus<SPI, D>,
+    variant: PhantomData<V>,
+    write_policy: Option<&'static dyn WritePolicy>,
+    skip_identical: bool,
 }
 
-impl<SPI> Eeprom25aa02e48<SPI>
+/// Microchip 25AA02E48 driver.
+pub type Eeprom25aa02e48<SPI, D = NoDelay> = Eeprom25aa02<SPI, E48, D>;
+
+/// Microchip 25AA02E64 driver.
+pub type Eeprom25aa02e64<SPI, D = NoDelay> = Eeprom25aa02<SPI, E64, D>;
+
+impl<SPI, V> Eeprom25aa02<SPI, V, NoDelay>
 where
-    SPI: embedded_hal::spi::SpiDevice,
+    SPI: Transport,
+    V: Variant,
 {
     /// Creates a new driver from a SPI bus.
     ///
@@ -84,7 +496,154 @@ where
     /// ```
     #[inline]
     pub fn new(spi: SPI) -> Self {
-        Eeprom25aa02e48 { spi }
+        Eeprom25aa02 {
+            bus: Bus::new(spi, None, false),
+            variant: PhantomData,
+            write_policy: None,
+            skip_identical: false,
+        }
+    }
+
+    /// Creates a new driver from a half-duplex (3-wire) SPI bus.
+    ///
+    /// This is for boards with SI and SO tied together, as permitted by the
+    /// datasheet.
+    /// Reads are sent as a [`Operation::Write`] of the command followed by
+    /// an [`Operation::Read`] of the data, never a transfer, and a delay of
+    /// [`HALF_DUPLEX_TURNAROUND_NS`] is inserted between the two so the first
+    /// data bit is valid before it is clocked in.
+    ///
+    /// The driver cannot release the shared data line itself.
+    /// The HAL must stop driving the line for [`Operation::Read`], for
+    /// example with a bidirectional (3-wire) SPI mode, otherwise the
+    /// controller and the EEPROM drive the line at the same time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS, HALF_DUPLEX_TURNAROUND_NS};
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::delay(HALF_DUPLEX_TURNAROUND_NS),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Eui48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new_half_duplex(spi);
+    /// let eui48: Eui48 = eeprom.read_eui48()?;
+    /// # assert_eq!(eui48, Eui48([0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[inline]
+    pub fn new_half_duplex(spi: SPI) -> Self {
+        Eeprom25aa02 {
+            bus: Bus::new(spi, None, true),
+            variant: PhantomData,
+            write_policy: None,
+            skip_identical: false,
+        }
+    }
+}
+
+#[cfg(feature = "embedded-hal-bus")]
+impl<BUS, CS, V> Eeprom25aa02<ExclusiveDevice<BUS, CS, embedded_hal_bus::spi::NoDelay>, V, NoDelay>
+where
+    BUS: SpiBus,
+    CS: OutputPin,
+    V: Variant,
+{
+    /// Creates a new driver from a SPI bus and a chip select pin.
+    ///
+    /// The pair is wrapped in an [`ExclusiveDevice`], so the bus must not be
+    /// shared with other devices.
+    /// The chip select pin is driven high before returning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let bus = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::flush(),
+    /// # ]);
+    /// # let cs = hal::digital::Mock::new(&[
+    /// #   hal::digital::Transaction::set(hal::digital::State::High),
+    /// #   hal::digital::Transaction::set(hal::digital::State::Low),
+    /// #   hal::digital::Transaction::set(hal::digital::State::High),
+    /// # ]);
+    /// # let mut cs_handle = cs.clone();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new_with_cs(bus, cs)?;
+    /// let mut buf: [u8; 2] = [0; 2];
+    /// eeprom.read(0x10, &mut buf)?;
+    /// assert_eq!(buf, [0x12, 0x34]);
+    /// # eeprom.free().bus_mut().done(); cs_handle.done();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn new_with_cs(bus: BUS, cs: CS) -> Result<Self, CS::Error> {
+        ExclusiveDevice::new_no_delay(bus, cs).map(Self::new)
+    }
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Creates a new driver from a SPI bus and a delay.
+    ///
+    /// The driver waits for the internal write cycle to complete after every
+    /// write, so back-to-back writes never fail silently.
+    /// The delay is used to pace polling of the STATUS register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x01]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// # let delay = hal::delay::NoopDelay::new();
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new_with_delay(spi, delay);
+    /// // returns after the write cycle has completed
+    /// eeprom.write_page(0x10, &[0x12; 16])?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[inline]
+    pub fn new_with_delay(spi: SPI, delay: D) -> Self {
+        Eeprom25aa02 {
+            bus: Bus::new(spi, Some(delay), false),
+            variant: PhantomData,
+            write_policy: None,
+            skip_identical: false,
+        }
     }
 
     /// Free the SPI bus from the device.
@@ -102,28 +661,42 @@ where
     /// ```
     #[inline]
     pub fn free(self) -> SPI {
-        self.spi
-    }
-
-    /// Context manager to ensure the write latch is always disabled after an operation.
-    #[inline(always)]
-    fn with_write_latch(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), SPI::Error> {
-        self.spi.write(&[instruction::WREN])?;
-        let result = self.spi.transaction(operations);
-        // write latch automatically resets on successful write
-        if result.is_err() {
-            self.spi.write(&[instruction::WRDI])?;
-        }
-        result
+        self.bus.free()
     }
 
-    /// Read from the EEPROM.
+    /// Size of the array in bytes.
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `address` - A byte address from 0x00 to 0xFF.
-    /// * `buf` - Buffer to read data into.
-    ///   The size of the buffer determines the number of bytes read.
+    /// ```
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.capacity(), 256);
+    /// assert_eq!(eeprom.page_size(), 16);
+    /// assert_eq!(eeprom.page_count(), 16);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        ARRAY_SIZE
+    }
+
+    /// Size of a write page in bytes.
+    #[inline]
+    pub const fn page_size(&self) -> usize {
+        PAGE_SIZE as usize
+    }
+
+    /// Number of write pages in the array.
+    #[inline]
+    pub const fn page_count(&self) -> usize {
+        PageIndex::COUNT
+    }
+
+    /// Bus health statistics.
     ///
     /// # Example
     ///
@@ -133,149 +706,2124 @@ where
     /// # let spi = hal::spi::Mock::new(&[
     /// #   hal::spi::Transaction::transaction_start(),
     /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
-    /// #   hal::spi::Transaction::transfer_in_place(vec![0x00; 64], vec![0x00; 64]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
     /// #   hal::spi::Transaction::transaction_end(),
     /// # ]);
-    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    /// use eeprom25aa02e48::{BusHealth, Eeprom25aa02e48};
     ///
-    /// let mut some_big_buf: [u8; 1024] = [0; 1024];
     /// let mut eeprom = Eeprom25aa02e48::new(spi);
-    /// // read 64 bytes starting at EEPROM address 0x00
-    /// eeprom.read(0x00, &mut some_big_buf[..64])?;
+    /// eeprom.read(0x00, &mut [0])?;
+    ///
+    /// let health: &BusHealth = eeprom.bus_health();
+    /// assert_eq!(health.transactions(), 1);
+    /// assert_eq!(health.consecutive_errors(), 0);
+    /// assert_eq!(health.last_error(), None);
     /// # let mut spi = eeprom.free(); spi.done();
-    /// # Ok::<(), embedded_hal::spi::ErrorKind>(())
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
     /// ```
+    #[cfg(feature = "bus-health")]
+    #[inline]
+    pub fn bus_health(&self) -> &BusHealth {
+        self.bus.health()
+    }
+
+    /// Reset the bus health statistics.
     ///
-    /// # Safety
+    /// This also clears the consecutive error count used by the automatic
+    /// recovery policy.
+    #[cfg(feature = "bus-health")]
+    #[inline]
+    pub fn reset_bus_health(&mut self) {
+        self.bus.reset_health();
+    }
+
+    /// Set the automatic recovery policy.
     ///
-    /// If the buffer length plus address exceeds the maximum address of `0xFF`
-    /// the address counter will roll over to `0x00`.
+    /// When set, the driver runs [`recover`](Self::recover) before the next
+    /// operation once `threshold` consecutive SPI transactions have failed.
+    /// That operation is not performed, instead it returns
+    /// [`Error::Recovered`] if the device recovered, or
+    /// [`Error::Unrecoverable`] if it did not.
     ///
-    /// # Panics
+    /// The policy is disabled by default.
     ///
-    /// The length of the buf may not exceed 256.
+    /// # Example
     ///
-    /// ```should_panic
+    /// ```
     /// # use embedded_hal_mock::eh1 as hal;
     /// # let spi = hal::spi::Mock::new(&[]);
+    /// use core::num::NonZeroU8;
     /// use eeprom25aa02e48::Eeprom25aa02e48;
     ///
-    /// let mut some_big_buf: [u8; 1024] = [0; 1024];
     /// let mut eeprom = Eeprom25aa02e48::new(spi);
-    /// eeprom.read(0x0, &mut some_big_buf)?;
+    /// eeprom.set_recovery_threshold(NonZeroU8::new(3));
     /// # let mut spi = eeprom.free(); spi.done();
-    /// # Ok::<(), embedded_hal::spi::ErrorKind>(())
     /// ```
-    pub fn read(&mut self, address: u8, buf: &mut [u8]) -> Result<(), SPI::Error> {
-        if buf.is_empty() {
-            Ok(())
-        } else {
-            // buffer is too large
-            assert!(buf.len() <= 256);
-            let cmd: [u8; 2] = [instruction::READ, address];
-            self.spi
-                .transaction(&mut [Operation::Write(&cmd), Operation::TransferInPlace(buf)])
-        }
+    #[inline]
+    pub fn set_recovery_threshold(&mut self, threshold: Option<NonZeroU8>) {
+        self.bus.set_recovery_threshold(threshold);
     }
 
-    /// Writes up to a page of data to the EEPROM.
+    /// Set the write policy.
     ///
-    /// # Arguments
+    /// The policy is consulted before every write to the array, writes it
+    /// denies return [`Error::WriteDenied`].
     ///
-    /// * `address` - A byte address from 0x00 to 0xFF.
-    /// * `data` - Data to write, must be less than or equal to the page size in length.
+    /// The default is [`DefaultWritePolicy`], which denies writes to the node
+    /// address.
     ///
     /// # Example
     ///
-    /// Write to the second page (page 1).
+    /// ```
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// use eeprom25aa02e48::{AllowAll, Eeprom25aa02e48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.set_write_policy(&AllowAll);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    #[inline]
+    pub fn set_write_policy(&mut self, policy: &'static dyn WritePolicy) {
+        self.write_policy = Some(policy);
+    }
+
+    /// Skip writing data that already matches the array.
+    ///
+    /// When enabled, [`write`](Self::write) reads each page before writing
+    /// it, and writes only the range of bytes that differ.
+    /// Pages that already match are not written, saving a write cycle.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
     ///
     /// ```
     /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock::eh1 as hal;
     /// # let spi = hal::spi::Mock::new(&[
     /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x01, 0x09, 0x03]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
     /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
     /// #   hal::spi::Transaction::transaction_end(),
     /// #   hal::spi::Transaction::transaction_start(),
-    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
-    /// #   hal::spi::Transaction::write_vec(vec![0x12; 16]),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x11]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x02]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x01, 0x02, 0x03]),
     /// #   hal::spi::Transaction::transaction_end(),
     /// # ]);
     /// use eeprom25aa02e48::Eeprom25aa02e48;
     ///
-    /// let data: [u8; 16] = [0x12; 16];
     /// let mut eeprom = Eeprom25aa02e48::new(spi);
-    /// eeprom.write_page(0x10, &data)?;
+    /// eeprom.set_skip_identical(true);
+    ///
+    /// // only the second byte differs, and is written
+    /// eeprom.write(0x10, &[0x01, 0x02, 0x03])?;
+    ///
+    /// // nothing is written
+    /// eeprom.write(0x10, &[0x01, 0x02, 0x03])?;
     /// # let mut spi = eeprom.free(); spi.done();
-    /// # Ok::<(), embedded_hal::spi::ErrorKind>(())
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
     /// ```
+    #[inline]
+    pub fn set_skip_identical(&mut self, enabled: bool) {
+        self.skip_identical = enabled;
+    }
+
+    /// Returns the range of `data` that differs from the array.
+    fn differing_range(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<Option<Range<usize>>, Error<SPI::Error>> {
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        let buf: &mut [u8] = &mut buf[..data.len()];
+        self.read(address, buf)?;
+        let differs = |(a, b): (&u8, &u8)| a != b;
+        let first: Option<usize> = buf.iter().zip(data).position(differs);
+        let last: Option<usize> = buf.iter().zip(data).rposition(differs);
+        Ok(first.zip(last).map(|(first, last)| first..last + 1))
+    }
+
+    /// Check a write against the protected block and the write policy.
+    fn check_write(&self, address: u8, len: usize) -> Result<(), Error<SPI::Error>> {
+        if len != 0 && usize::from(address) + len > usize::from(PROTECTED_BLOCK_ADDRESS) {
+            return Err(Error::ProtectedRegion);
+        }
+        self.check_write_policy(address, len)
+    }
+
+    /// Check a write against the write policy.
+    fn check_write_policy(&self, address: u8, len: usize) -> Result<(), Error<SPI::Error>> {
+        let policy: &dyn WritePolicy = self.write_policy.unwrap_or(&DefaultWritePolicy);
+        if policy.allows(address, len) {
+            Ok(())
+        } else {
+            Err(Error::WriteDenied)
+        }
+    }
+
+    /// Probe the device.
     ///
-    /// # Panics
+    /// This sends the write disable instruction, then checks that the write
+    /// enable latch reads back as cleared in the STATUS register.
     ///
-    /// The data length must be less than or equal to the page size (16).
+    /// # Example
     ///
-    /// ```should_panic
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock::eh1 as hal;
-    /// # let spi = hal::spi::Mock::new(&[]);
-    /// # let pin = hal::digital::Mock::new(&[]);
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRDI]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
     /// use eeprom25aa02e48::Eeprom25aa02e48;
     ///
-    /// let data: [u8; 17] = [0x00; 17];
     /// let mut eeprom = Eeprom25aa02e48::new(spi);
-    /// eeprom.write_page(0, &data)?;
+    /// eeprom.probe()?;
     /// # let mut spi = eeprom.free(); spi.done();
-    /// # Ok::<(), embedded_hal::spi::ErrorKind>(())
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
     /// ```
+    #[inline]
+    pub fn probe(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.bus.probe()
+    }
+
+    /// Read the STATUS register.
     ///
-    /// The address must be page aligned.
+    /// # Example
     ///
-    /// ```should_panic
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock::eh1 as hal;
-    /// # let spi = hal::spi::Mock::new(&[]);
-    /// # let pin = hal::digital::Mock::new(&[]);
-    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x0C]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, StatusRegister};
     ///
-    /// let data: [u8; 16] = [0x00; 16];
     /// let mut eeprom = Eeprom25aa02e48::new(spi);
-    /// eeprom.write_page(1, &data)?;
+    /// let status: StatusRegister = eeprom.read_status()?;
+    /// assert!(!status.wip());
+    /// assert!(!status.wel());
+    /// assert!(status.bp0());
+    /// assert!(status.bp1());
     /// # let mut spi = eeprom.free(); spi.done();
-    /// # Ok::<(), embedded_hal::spi::ErrorKind>(())
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
     /// ```
-    pub fn write_page(&mut self, address: u8, data: &[u8]) -> Result<(), SPI::Error> {
-        assert!(address % PAGE_SIZE == 0);
-        if data.is_empty() {
-            Ok(())
-        } else {
-            assert!(data.len() <= PAGE_SIZE as usize);
-            let cmd: [u8; 2] = [instruction::WRITE, address];
-            self.with_write_latch(&mut [Operation::Write(&cmd), Operation::Write(data)])
-        }
+    #[inline]
+    pub fn read_status(&mut self) -> Result<StatusRegister, Error<SPI::Error>> {
+        self.bus.read_status()
     }
 
-    /// Read the EUI-48 MAC address from the EEPROM.
+    /// Read the array block write protection.
     ///
     /// # Example
     ///
     /// ```
-    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+    /// # use eeprom25aa02e48::instruction;
     /// # use embedded_hal_mock::eh1 as hal;
     /// # let spi = hal::spi::Mock::new(&[
     /// #   hal::spi::Transaction::transaction_start(),
-    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
-    /// #   hal::spi::Transaction::transfer_in_place(vec![0; 6], vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x04]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{BlockProtect, Eeprom25aa02e48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.block_protection()?, BlockProtect::UpperQuarter);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn block_protection(&mut self) -> Result<BlockProtect, Error<SPI::Error>> {
+        Ok(self.read_status()?.block_protection())
+    }
+
+    /// Set the array block write protection.
+    ///
+    /// This sets the write enable latch, then writes the BP1 and BP0 bits of
+    /// the STATUS register.
+    /// Writing the STATUS register starts an internal write cycle.
+    ///
+    /// Drivers created with [`new_with_delay`](Self::new_with_delay) wait
+    /// for the write cycle to complete before returning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRSR, 0x08]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{BlockProtect, Eeprom25aa02e48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.set_block_protection(BlockProtect::UpperHalf)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn set_block_protection(&mut self, protect: BlockProtect) -> Result<(), Error<SPI::Error>> {
+        self.bus.begin()?;
+        let cmd: [u8; 2] = commands::encode_wrsr(protect.bits());
+        self.bus.with_write_latch(&mut [Operation::Write(&cmd)])?;
+        self.bus.auto_wait()?;
+        Ok(())
+    }
+
+    /// Write protect the upper quarter of the array, which contains the
+    /// EUI-48.
+    ///
+    /// This is equivalent to
+    /// [`set_block_protection(BlockProtect::UpperQuarter)`](Self::set_block_protection).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRSR, 0x04]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.lock_upper_block()?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[inline]
+    pub fn lock_upper_block(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.set_block_protection(BlockProtect::UpperQuarter)
+    }
+
+    /// Returns `true` if an internal write cycle is in progress.
+    ///
+    /// This reads the write-in-progress bit of the STATUS register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x01]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert!(eeprom.is_busy()?);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[inline]
+    pub fn is_busy(&mut self) -> Result<bool, Error<SPI::Error>> {
+        self.bus.is_busy()
+    }
+
+    /// Wait for an internal write cycle to complete.
+    ///
+    /// This polls the write-in-progress bit of the STATUS register until it
+    /// clears, and returns immediately if no write cycle is in progress.
+    ///
+    /// Drivers created with [`new_with_delay`](Self::new_with_delay) wait
+    /// between polls, other drivers poll continuously.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if the write cycle does not complete within
+    /// twice [`WRITE_CYCLE_TIME_MS`], for example when the device is missing
+    /// and MISO is pulled high.
+    /// Drivers without a delay give up after a fixed number of polls, which
+    /// takes at least this long at the maximum clock frequency.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut expectations = vec![];
+    /// # for _ in 0..200 {
+    /// #   expectations.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0xFF]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// # let delay = hal::delay::NoopDelay::new();
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new_with_delay(spi, delay);
+    /// assert_eq!(eeprom.wait_write_complete(), Err(Error::Timeout));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x01]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_page(0x10, &[0x12; 16])?;
+    /// eeprom.wait_write_complete()?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[inline]
+    pub fn wait_write_complete(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.bus.wait_write_complete()
+    }
+
+    /// Recover the device after a failure.
+    ///
+    /// This runs [`probe`](Self::probe), which also disables the write enable
+    /// latch, and clears the consecutive error count used by the automatic
+    /// recovery policy on success.
+    #[inline]
+    pub fn recover(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.bus.recover()
+    }
+
+    /// Read from the EEPROM.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `buf` - Buffer to read data into.
+    ///   The size of the buffer determines the number of bytes read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00; 64]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut some_big_buf: [u8; 1024] = [0; 1024];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// // read 64 bytes starting at EEPROM address 0x00
+    /// eeprom.read(0x00, &mut some_big_buf[..64])?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The length of the buf may not exceed 256.
+    ///
+    /// ```
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut some_big_buf: [u8; 1024] = [0; 1024];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.read(0x0, &mut some_big_buf), Err(Error::DataTooLong));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    ///
+    /// The read may not extend past the end of the array at 0xFF, where the
+    /// address counter would roll over to 0x00.
+    ///
+    /// ```
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut buf: [u8; 8] = [0; 8];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.read(0xFC, &mut buf), Err(Error::OutOfBounds));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    pub fn read(&mut self, address: u8, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        if buf.len() > ARRAY_SIZE {
+            Err(Error::DataTooLong)
+        } else if usize::from(address) + buf.len() > ARRAY_SIZE {
+            Err(Error::OutOfBounds)
+        } else {
+            self.read_unchecked(address, buf)
+        }
+    }
+
+    /// Read from the EEPROM, rolling over to 0x00 at the end of the array.
+    ///
+    /// This is identical to [`read`](Self::read), except reads extending
+    /// past 0xFF continue from 0x00, as the hardware address counter does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0xFE]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut buf: [u8; 4] = [0; 4];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// // reads 0xFE, 0xFF, 0x00, 0x01
+    /// eeprom.wrapping_read(0xFE, &mut buf)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The length of the buf may not exceed 256.
+    pub fn wrapping_read(&mut self, address: u8, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        if buf.len() > ARRAY_SIZE {
+            Err(Error::DataTooLong)
+        } else {
+            self.read_unchecked(address, buf)
+        }
+    }
+
+    /// Read from the EEPROM without checking the length.
+    fn read_unchecked(&mut self, address: u8, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+        self.bus.begin()?;
+        self.bus
+            .read(&commands::encode_read(address), buf, &mut [])?;
+        Ok(())
+    }
+
+    /// Read into `head` then `tail` in a single transaction, for data
+    /// followed by a CRC or tag.
+    pub(crate) fn read_split(
+        &mut self,
+        address: u8,
+        head: &mut [u8],
+        tail: &mut [u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        let len: usize = head.len() + tail.len();
+        if len > ARRAY_SIZE {
+            return Err(Error::DataTooLong);
+        } else if usize::from(address) + len > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        } else if head.is_empty() {
+            return self.read_unchecked(address, tail);
+        } else if tail.is_empty() {
+            return self.read_unchecked(address, head);
+        }
+        self.bus.begin()?;
+        self.bus.read(&commands::encode_read(address), head, tail)?;
+        Ok(())
+    }
+
+    /// Read a single byte from the EEPROM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x05]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xAB]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let value: u8 = eeprom.read_byte(0x05)?;
+    /// # assert_eq!(value, 0xAB);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn read_byte(&mut self, address: u8) -> Result<u8, Error<SPI::Error>> {
+        let mut buf: [u8; 1] = [0];
+        self.read(address, &mut buf)?;
+        Ok(buf[0])
+    }
+
+    /// Read a fixed number of bytes from the EEPROM into an array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x20]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let serial: u32 = u32::from_le_bytes(eeprom.read_array::<4>(0x20)?);
+    /// # assert_eq!(serial, 0x78563412);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// `N` may not exceed 256, and the read may not extend past the end of
+    /// the array.
+    pub fn read_array<const N: usize>(
+        &mut self,
+        address: u8,
+    ) -> Result<[u8; N], Error<SPI::Error>> {
+        let mut buf: [u8; N] = [0; N];
+        self.read(address, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Read the entire EEPROM array in one transaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xFF; 256]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let image: [u8; 256] = eeprom.read_all()?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[inline]
+    pub fn read_all(&mut self) -> Result<[u8; ARRAY_SIZE], Error<SPI::Error>> {
+        self.read_array(0x00)
+    }
+
+    /// Low-level access to the EEPROM instructions, see [`ll`].
+    #[inline]
+    pub fn ll(&mut self) -> ll::LowLevel<'_, SPI, V, D> {
+        ll::LowLevel { eeprom: self }
+    }
+
+    /// Run a custom write sequence with the write enable latch set.
+    ///
+    /// The write enable latch is set before calling `f`, and reset if `f`
+    /// returns an error, so a failed sequence never leaves the EEPROM write
+    /// enabled.
+    /// The latch resets automatically after a successful write instruction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x20, 0x12, 0x34]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{instruction, Eeprom25aa02e48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.with_write_enabled(|ll| ll.instruction(instruction::WRITE, &[0x20, 0x12, 0x34]))?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn with_write_enabled<T>(
+        &mut self,
+        f: impl FnOnce(&mut ll::LowLevel<'_, SPI, V, D>) -> Result<T, Error<SPI::Error>>,
+    ) -> Result<T, Error<SPI::Error>> {
+        self.bus.begin()?;
+        let mut ll = self.ll();
+        ll.wren()?;
+        let result = f(&mut ll);
+        if result.is_err() {
+            ll.wrdi()?;
+        }
+        result
+    }
+
+    /// Iterate over the pages containing a range of addresses.
+    ///
+    /// Each page is read when the iterator is advanced, and yielded with its
+    /// page aligned address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0xE0]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xFF; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0xF0]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xFF; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// for page in eeprom.pages(0xE0..) {
+    ///     let (address, data) = page?;
+    ///     println!("{address:02X}: {data:02X?}");
+    /// }
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn pages(&mut self, range: impl RangeBounds<u8>) -> Pages<'_, SPI, V, D> {
+        let range: Range<usize> = address_range(range);
+        let pages: Range<usize> = if range.is_empty() {
+            0..0
+        } else {
+            range.start / PAGE_SIZE as usize..(range.end - 1) / PAGE_SIZE as usize + 1
+        };
+        Pages {
+            eeprom: self,
+            pages,
+        }
+    }
+
+    /// Iterate over the bytes in a range of addresses.
+    ///
+    /// Bytes are read in small chunks as the iterator is advanced, which
+    /// allows parsing the contents without buffering the entire array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::read_vec(vec![b'h', b'i', 0, 0xFF]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let len: usize = eeprom
+    ///     .bytes(0x00..0x04)
+    ///     .take_while(|byte| !matches!(byte, Ok(0)))
+    ///     .count();
+    /// assert_eq!(len, 2);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn bytes(&mut self, range: impl RangeBounds<u8>) -> Bytes<'_, SPI, V, D> {
+        Bytes::new(self, address_range(range))
+    }
+
+    /// Returns a window onto `len` bytes of the EEPROM starting at `start`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x2E]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let mut network = eeprom.region(0x20, 0x10)?;
+    /// network.write(0x0E, &[0x12, 0x34])?;
+    /// assert_eq!(network.write(0x0F, &[0x12, 0x34]), Err(Error::OutOfBounds));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The region may not extend past the end of the array at 0xFF, this
+    /// returns [`Error::OutOfBounds`].
+    pub fn region(
+        &mut self,
+        start: u8,
+        len: usize,
+    ) -> Result<EepromRegion<'_, SPI, V, D>, Error<SPI::Error>> {
+        if usize::from(start) + len > ARRAY_SIZE {
+            Err(Error::OutOfBounds)
+        } else {
+            Ok(EepromRegion {
+                eeprom: self,
+                start,
+                len,
+            })
+        }
+    }
+
+    /// Read from the EEPROM, correcting bit errors with a majority vote.
+    ///
+    /// This is intended for noisy links, such as long cables, where
+    /// occasional bit errors on MISO are expected.
+    ///
+    /// The region is read twice, in chunks of [`PAGE_SIZE`] bytes for the
+    /// second read.
+    /// When a chunk differs between the two reads it is read a third time,
+    /// and each bit is set to the value seen in at least two of the reads.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `buf` - Buffer to read data into.
+    ///   The size of the buffer determines the number of bytes read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x35]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Vote};
+    ///
+    /// let mut buf: [u8; 2] = [0; 2];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let vote: Vote = eeprom.read_robust(0x00, &mut buf)?;
+    /// assert_eq!(vote, Vote::Corrected);
+    /// assert_eq!(buf, [0x12, 0x34]);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The length of the buf may not exceed 256.
+    pub fn read_robust(&mut self, address: u8, buf: &mut [u8]) -> Result<Vote, Error<SPI::Error>> {
+        self.read(address, buf)?;
+
+        let mut vote: Vote = Vote::Unanimous;
+        let mut second: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        let mut third: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        for (n, chunk) in buf.chunks_mut(PAGE_SIZE as usize).enumerate() {
+            let chunk_address: u8 = address.wrapping_add((n * PAGE_SIZE as usize) as u8);
+            let second: &mut [u8] = &mut second[..chunk.len()];
+            self.read(chunk_address, second)?;
+            if chunk != second {
+                let third: &mut [u8] = &mut third[..chunk.len()];
+                self.read(chunk_address, third)?;
+                chunk
+                    .iter_mut()
+                    .zip(second.iter().zip(third.iter()))
+                    .for_each(|(a, (b, c))| *a = (*a & b) | (*a & c) | (b & c));
+                vote = Vote::Corrected;
+            }
+        }
+        Ok(vote)
+    }
+
+    /// Writes up to a page of data to the EEPROM.
+    ///
+    /// Drivers created with [`new_with_delay`](Self::new_with_delay) wait
+    /// for the write cycle to complete before returning.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `data` - Data to write, must be less than or equal to the page size in length.
+    ///
+    /// # Example
+    ///
+    /// Write to the second page (page 1).
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let data: [u8; 16] = [0x12; 16];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_page(0x10, &data)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The data length must be less than or equal to the page size (16).
+    ///
+    /// ```
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let data: [u8; 17] = [0x00; 17];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.write_page(0, &data), Err(Error::DataTooLong));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    ///
+    /// The address must be page aligned.
+    ///
+    /// ```
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let data: [u8; 16] = [0x00; 16];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.write_page(1, &data), Err(Error::NotPageAligned));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    ///
+    /// The upper quarter of the array is write protected.
+    ///
+    /// ```
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let data: [u8; 16] = [0x00; 16];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.write_page(0xC0, &data), Err(Error::ProtectedRegion));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    pub fn write_page(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if !address.is_multiple_of(PAGE_SIZE) {
+            Err(Error::NotPageAligned)
+        } else if data.len() > PAGE_SIZE as usize {
+            Err(Error::DataTooLong)
+        } else if data.is_empty() {
+            Ok(())
+        } else {
+            self.write_within_page(address, data)?;
+            self.bus.auto_wait()?;
+            Ok(())
+        }
+    }
+
+    /// Writes data of any length to the EEPROM.
+    ///
+    /// The data is split into page aligned chunks, and the driver waits
+    /// for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `data` - Data to write.
+    ///
+    /// # Example
+    ///
+    /// Write 20 bytes starting at 0x0C, spanning the first and second page.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut transactions = Vec::new();
+    /// # for (address, len) in [(0x0C, 4), (0x10, 16)] {
+    /// #   transactions.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(vec![0xAB; len]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x01]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&transactions);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let data: [u8; 20] = [0xAB; 20];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write(0x0C, &data)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The data may not extend past the end of the array at 0xFF.
+    ///
+    /// ```
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let data: [u8; 8] = [0x00; 8];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.write(0xFC, &data), Err(Error::OutOfBounds));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    pub fn write(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if usize::from(address) + data.len() > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        self.check_write(address, data.len())?;
+
+        let start: usize = address.into();
+        for chunk in page_chunks(start..start + data.len()) {
+            let data: &[u8] = &data[chunk.start - start..chunk.end - start];
+            if self.start_chunk_write(chunk.start as u8, data)? {
+                self.wait_write_complete()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the concatenation of `parts`, such as a header, data, and a
+    /// CRC, without copying them into one buffer.
+    ///
+    /// This is [`write`](Self::write), one page at a time.
+    pub(crate) fn write_parts(
+        &mut self,
+        address: u8,
+        parts: &[&[u8]],
+    ) -> Result<(), Error<SPI::Error>> {
+        let len: usize = parts.iter().map(|part| part.len()).sum();
+        self.write_iter(
+            address,
+            len,
+            parts.iter().flat_map(|part| part.iter().copied()),
+        )
+    }
+
+    /// Write `len` bytes produced by an iterator, such as encoded data,
+    /// without collecting them into one buffer.
+    ///
+    /// This is [`write`](Self::write), one page at a time.
+    pub(crate) fn write_iter(
+        &mut self,
+        address: u8,
+        len: usize,
+        bytes: impl IntoIterator<Item = u8>,
+    ) -> Result<(), Error<SPI::Error>> {
+        if usize::from(address) + len > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        self.check_write(address, len)?;
+
+        let mut bytes = bytes.into_iter();
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        let start: usize = address.into();
+        for chunk in page_chunks(start..start + len) {
+            let data: &mut [u8] = &mut buf[..chunk.len()];
+            data.iter_mut()
+                .zip(&mut bytes)
+                .for_each(|(dst, src)| *dst = src);
+            if self.start_chunk_write(chunk.start as u8, data)? {
+                self.wait_write_complete()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Start writing data that does not cross a page boundary, without
+    /// waiting for the write cycle to complete.
+    ///
+    /// Returns `false` if no write cycle was started because the data is
+    /// already stored.
+    fn start_chunk_write(&mut self, address: u8, data: &[u8]) -> Result<bool, Error<SPI::Error>> {
+        let (address, data): (u8, &[u8]) = if self.skip_identical {
+            match self.differing_range(address, data)? {
+                Some(range) => (address + range.start as u8, &data[range]),
+                None => return Ok(false),
+            }
+        } else {
+            (address, data)
+        };
+        self.write_within_page(address, data)?;
+        Ok(true)
+    }
+
+    /// Fill a region of the EEPROM with a constant value.
+    ///
+    /// The region is split into page aligned chunks, and the driver waits
+    /// for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `len` - Number of bytes to fill.
+    /// * `value` - Value to fill the region with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut transactions = Vec::new();
+    /// # for (address, len) in [(0x38, 8), (0x40, 4)] {
+    /// #   transactions.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(vec![0x00; len]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&transactions);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.fill(0x38, 12, 0x00)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The region may not extend past the end of the array at 0xFF, this
+    /// returns [`Error::OutOfBounds`].
+    pub fn fill(&mut self, address: u8, len: usize, value: u8) -> Result<(), Error<SPI::Error>> {
+        if usize::from(address) + len > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        self.check_write(address, len)?;
+
+        let start: usize = address.into();
+        let buf: [u8; PAGE_SIZE as usize] = [value; PAGE_SIZE as usize];
+        for chunk in page_chunks(start..start + len) {
+            self.write_within_page(chunk.start as u8, &buf[..chunk.len()])?;
+            self.wait_write_complete()?;
+        }
+        Ok(())
+    }
+
+    /// Copy a region of the EEPROM to another region of the EEPROM.
+    ///
+    /// Data is copied through a buffer of up to one page, in chunks aligned
+    /// to the destination pages.
+    /// Overlapping regions are handled by copying in the appropriate
+    /// direction.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Source byte address from 0x00 to 0xFF.
+    /// * `dst` - Destination byte address from 0x00 to 0xFF.
+    /// * `len` - Number of bytes to copy.
+    ///
+    /// # Example
+    ///
+    /// Move a 4 byte block from 0x00 to 0x02.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x02]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12, 0x34, 0x56, 0x78]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.copy_within(0x00, 0x02, 4)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Neither region may extend past the end of the array at 0xFF, this
+    /// returns [`Error::OutOfBounds`].
+    pub fn copy_within(&mut self, src: u8, dst: u8, len: usize) -> Result<(), Error<SPI::Error>> {
+        if usize::from(src.max(dst)) + len > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        self.check_write(dst, len)?;
+
+        let start: usize = dst.into();
+        let mut chunks: PageChunks = page_chunks(start..start + len);
+        // copy backwards when the destination overlaps the end of the source
+        let backwards: bool = dst > src;
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        while let Some(chunk) = if backwards {
+            chunks.next_back()
+        } else {
+            chunks.next()
+        } {
+            let buf: &mut [u8] = &mut buf[..chunk.len()];
+            self.read((chunk.start - start) as u8 + src, buf)?;
+            self.write_within_page(chunk.start as u8, buf)?;
+            self.wait_write_complete()?;
+        }
+        Ok(())
+    }
+
+    /// Write a single byte to the EEPROM.
+    ///
+    /// Drivers created with [`new_with_delay`](Self::new_with_delay) wait
+    /// for the write cycle to complete before returning.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `value` - Byte to write.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x05]),
+    /// #   hal::spi::Transaction::write_vec(vec![0xAB]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_byte(0x05, 0xAB)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn write_byte(&mut self, address: u8, value: u8) -> Result<(), Error<SPI::Error>> {
+        self.write_within_page(address, &[value])?;
+        self.bus.auto_wait()
+    }
+
+    /// Erase a page by filling it with `0xFF`.
+    ///
+    /// Waits for the write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A page aligned byte address from 0x00 to 0xFF.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x20]),
+    /// #   hal::spi::Transaction::write_vec(vec![0xFF; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.erase_page(0x20)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The address must be page aligned.
+    pub fn erase_page(&mut self, address: u8) -> Result<(), Error<SPI::Error>> {
+        if !address.is_multiple_of(PAGE_SIZE) {
+            return Err(Error::NotPageAligned);
+        }
+        self.write_within_page(address, &[0xFF; PAGE_SIZE as usize])?;
+        self.wait_write_complete()
+    }
+
+    /// Erase the array by filling it with `0xFF`.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// The upper quarter of the array, starting at
+    /// [`PROTECTED_BLOCK_ADDRESS`], contains the EUI-48 and is not erased.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut transactions = Vec::new();
+    /// # for address in (0x00..0xC0).step_by(16) {
+    /// #   transactions.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(vec![0xFF; 16]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// # }
+    /// # transactions.extend([
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0xFA]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// # let spi = hal::spi::Mock::new(&transactions);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Eui48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.erase_all()?;
+    ///
+    /// // the EUI-48 is kept
+    /// let eui48: Eui48 = eeprom.read_eui48()?;
+    /// assert_eq!(eui48, Eui48([0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[inline]
+    pub fn erase_all(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.factory_reset_range(..PROTECTED_BLOCK_ADDRESS)
+    }
+
+    /// Writes a full page of data to the EEPROM.
+    ///
+    /// This is identical to [`write_page`](Self::write_page), except the data
+    /// length is checked at compile time.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A page aligned byte address from 0x00 to 0xFF.
+    /// * `data` - Data to write.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, PAGE_SIZE};
+    ///
+    /// let data: [u8; PAGE_SIZE as usize] = [0x12; PAGE_SIZE as usize];
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_full_page(0x10, &data)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The address must be page aligned.
+    #[inline]
+    pub fn write_full_page(
+        &mut self,
+        address: u8,
+        data: &[u8; PAGE_SIZE as usize],
+    ) -> Result<(), Error<SPI::Error>> {
+        self.write_page(address, data)
+    }
+
+    /// Writes up to a page of data to the EEPROM, including the normally
+    /// write-protected upper quarter of the array.
+    ///
+    /// This is identical to [`write_page`](Self::write_page), except writes
+    /// overlapping [`PROTECTED_BLOCK_ADDRESS`] do not return
+    /// [`Error::ProtectedRegion`].
+    /// It is intended for parts and configurations where the upper quarter is
+    /// writable, on the 25AA02E48 and 25AA02E64 these writes are silently
+    /// ignored.
+    ///
+    /// The [`WritePolicy`] still applies, which denies writes to the node
+    /// address by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0xC0]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, PROTECTED_BLOCK_ADDRESS};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_page_unchecked_region(PROTECTED_BLOCK_ADDRESS, &[0x12; 16])?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same as [`write_page`](Self::write_page), except for
+    /// [`Error::ProtectedRegion`].
+    pub fn write_page_unchecked_region(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        if !address.is_multiple_of(PAGE_SIZE) {
+            Err(Error::NotPageAligned)
+        } else if data.len() > PAGE_SIZE as usize {
+            Err(Error::DataTooLong)
+        } else if data.is_empty() {
+            Ok(())
+        } else {
+            self.write_within_page_unchecked_region(address, data)?;
+            self.bus.auto_wait()?;
+            Ok(())
+        }
+    }
+
+    /// Write data that does not cross a page boundary.
+    ///
+    /// The address does not need to be page aligned.
+    fn write_within_page(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        self.check_write(address, data.len())?;
+        self.write_within_page_unchecked_region(address, data)
+    }
+
+    /// Write data that does not cross a page boundary, without checking the
+    /// protected block.
+    fn write_within_page_unchecked_region(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        debug_assert!(usize::from(address % PAGE_SIZE) + data.len() <= PAGE_SIZE as usize);
+        self.check_write_policy(address, data.len())?;
+        self.bus.begin()?;
+        let cmd: [u8; 2] = commands::encode_write(address);
+        self.bus
+            .with_write_latch(&mut [Operation::Write(&cmd), Operation::Write(data)])?;
+        Ok(())
+    }
+
+    /// Writes up to a page of data to the EEPROM, then reads it back to
+    /// verify the write.
+    ///
+    /// Waits for the write cycle before reading the page back, see
+    /// [write cycles](crate#write-cycles).
+    /// This detects writes silently discarded by the WP pin or block
+    /// protection.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A page aligned byte address from 0x00 to 0xFF.
+    /// * `data` - Data to write, must be less than or equal to the page size in length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x40]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x40]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xFF; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// // the WP pin is asserted
+    /// assert_eq!(
+    ///     eeprom.write_page_verified(0x40, &[0x12; 16]),
+    ///     Err(Error::VerifyFailed { offset: 0 })
+    /// );
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    pub fn write_page_verified(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        self.write_page(address, data)?;
+        if !self.bus.has_delay() {
+            self.wait_write_complete()?;
+        }
+        self.verify(address, data)
+    }
+
+    /// Read a page, modify it with a closure, and write it back if it changed.
+    ///
+    /// The write is skipped when the closure leaves the page unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A page aligned byte address from 0x00 to 0xFF.
+    /// * `f` - Closure to modify the page.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the page was written.
+    ///
+    /// # Example
+    ///
+    /// Set a flag in the first byte of the second page (page 1).
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut page = vec![0x00; 16];
+    /// # page[0] = 0x01;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write_vec(page),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let written: bool = eeprom.update(0x10, |page| page[0] |= 0x01)?;
+    /// assert!(written);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The address must be page aligned.
+    pub fn update(
+        &mut self,
+        address: u8,
+        f: impl FnOnce(&mut [u8; PAGE_SIZE as usize]),
+    ) -> Result<bool, Error<SPI::Error>> {
+        if !address.is_multiple_of(PAGE_SIZE) {
+            return Err(Error::NotPageAligned);
+        }
+
+        let mut original: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        self.read(address, &mut original)?;
+        let mut page: [u8; PAGE_SIZE as usize] = original;
+        f(&mut page);
+        if page == original {
+            Ok(false)
+        } else {
+            self.write_page(address, &page)?;
+            Ok(true)
+        }
+    }
+
+    /// Write a list of pages, verifying each page after it is written.
+    ///
+    /// Each page is read first, and pages that already hold the data are
+    /// skipped without a write cycle.
+    /// After a page is written the driver polls the STATUS register, waiting
+    /// with `delay` between polls, and reads the page back as soon as the
+    /// write cycle completes rather than after the maximum write cycle time.
+    ///
+    /// The EEPROM runs one write cycle at a time, and cannot be read during
+    /// a write cycle, so the order of the pages does not change the total
+    /// wait time.
+    /// The pages are written in the order given.
+    ///
+    /// A failure on one page does not stop the remaining pages from being
+    /// written.
+    ///
+    /// # Arguments
+    ///
+    /// * `pages` - Page aligned addresses and the data to write to them.
+    /// * `delay` - Delay between STATUS register polls.
+    /// * `report` - Called with the index in `pages` and the result of every
+    ///   page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xFF; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x01]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x80]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x34; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// # let mut delay = hal::delay::NoopDelay::new();
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let pages: [(u8, &[u8; 16]); 3] = [(0x10, &[0x12; 16]), (0x80, &[0x34; 16]), (0x23, &[0; 16])];
+    /// let mut results = [Ok(()); 3];
+    /// eeprom.write_pages(&pages, &mut delay, |index, result| results[index] = result);
+    /// assert_eq!(results, [Ok(()), Ok(()), Err(Error::NotPageAligned)]);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    pub fn write_pages(
+        &mut self,
+        pages: &[(u8, &[u8; PAGE_SIZE as usize])],
+        delay: &mut impl DelayNs,
+        mut report: impl FnMut(usize, Result<(), Error<SPI::Error>>),
+    ) {
+        for (index, &(address, data)) in pages.iter().enumerate() {
+            report(index, self.write_page_if_changed(address, data, delay));
+        }
+    }
+
+    /// Write and verify a page, unless it already holds the data.
+    fn write_page_if_changed(
+        &mut self,
+        address: u8,
+        data: &[u8; PAGE_SIZE as usize],
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Error<SPI::Error>> {
+        if !address.is_multiple_of(PAGE_SIZE) {
+            return Err(Error::NotPageAligned);
+        }
+        let current: [u8; PAGE_SIZE as usize] = self.read_array(address)?;
+        if current == *data {
+            return Ok(());
+        }
+        self.write_within_page(address, data)?;
+        self.bus.wait_write_complete_with(delay)?;
+        self.verify(address, data)
+    }
+
+    /// Compare a region of the EEPROM against expected data.
+    ///
+    /// The region is read in chunks of [`PAGE_SIZE`] bytes, and the offset of
+    /// the first mismatching byte is reported in [`Error::VerifyFailed`].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A byte address from 0x00 to 0xFF.
+    /// * `expected` - Expected data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x08]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00; 16]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x18]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00, 0x00, 0x01, 0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(
+    ///     eeprom.verify(0x08, &[0x00; 20]),
+    ///     Err(Error::VerifyFailed { offset: 18 })
+    /// );
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The region may not extend past the end of the array at 0xFF, this
+    /// returns [`Error::OutOfBounds`].
+    pub fn verify(&mut self, address: u8, expected: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if usize::from(address) + expected.len() > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        for (n, chunk) in expected.chunks(PAGE_SIZE as usize).enumerate() {
+            let offset: usize = n * PAGE_SIZE as usize;
+            let buf: &mut [u8] = &mut buf[..chunk.len()];
+            self.read(address + offset as u8, buf)?;
+            if let Some(position) = buf.iter().zip(chunk.iter()).position(|(a, b)| a != b) {
+                return Err(Error::VerifyFailed {
+                    offset: offset + position,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy a range of data from this EEPROM to another EEPROM, verifying
+    /// the destination.
+    ///
+    /// Data is streamed through a buffer of up to one page, and the driver
+    /// waits for each write cycle of the destination, see
+    /// [write cycles](crate#write-cycles).
+    /// Any part of the range inside the protected block, from
+    /// [`PROTECTED_BLOCK_ADDRESS`] upwards, is skipped so the EUI-48 of the
+    /// destination is never written.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - Destination EEPROM.
+    /// * `range` - Range of addresses to copy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let src_spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x0E]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x56]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// # let dst_spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x0E]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x0E]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x56]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x56]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    ///
+    /// let mut src = Eeprom25aa02e48::new(src_spi);
+    /// let mut dst = Eeprom25aa02e48::new(dst_spi);
+    /// src.clone_into(&mut dst, 0x0E..0x11)?;
+    /// # let mut spi = src.free(); spi.done();
+    /// # let mut spi = dst.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::CloneError<embedded_hal::spi::ErrorKind, embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn clone_into<SPI2, V2, D2>(
+        &mut self,
+        dst: &mut Eeprom25aa02<SPI2, V2, D2>,
+        range: impl RangeBounds<u8>,
+    ) -> Result<(), CloneError<SPI::Error, SPI2::Error>>
+    where
+        SPI2: Transport,
+        V2: Variant,
+        D2: DelayNs,
+    {
+        let range: Range<usize> = address_range(range);
+        let end: usize = range.end.min(PROTECTED_BLOCK_ADDRESS.into());
+
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        for chunk in page_chunks(range.start..end) {
+            let address: usize = chunk.start;
+            let buf: &mut [u8] = &mut buf[..chunk.len()];
+
+            self.read(address as u8, buf).map_err(CloneError::Source)?;
+            dst.write_within_page(address as u8, buf)
+                .map_err(CloneError::Destination)?;
+            dst.wait_write_complete().map_err(CloneError::Destination)?;
+            dst.verify(address as u8, buf).map_err(|e| match e {
+                Error::VerifyFailed { offset } => CloneError::Destination(Error::VerifyFailed {
+                    offset: offset + address - range.start,
+                }),
+                e => CloneError::Destination(e),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Read the node address programmed into the chip from the factory.
+    ///
+    /// This is the EUI-48 MAC address for the 25AA02E48, and the EUI-64 node
+    /// address for the 25AA02E64.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI64_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI64_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00, 0x04, 0xA3, 0xFF, 0xFE, 0x12, 0x34, 0x56]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e64;
+    ///
+    /// let mut eeprom = Eeprom25aa02e64::new(spi);
+    /// let node_address: [u8; 8] = eeprom.read_node_address()?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # assert_eq!(node_address, [0x00, 0x04, 0xA3, 0xFF, 0xFE, 0x12, 0x34, 0x56]);
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn read_node_address(&mut self) -> Result<V::NodeAddress, Error<SPI::Error>> {
+        let mut node_address: V::NodeAddress = V::NodeAddress::default();
+        self.read(V::NODE_ADDRESS_MEMORY_ADDRESS, node_address.as_mut())?;
+        Ok(node_address)
+    }
+}
+
+impl<SPI, D> Eeprom25aa02<SPI, E48, D>
+where
+    SPI: Transport,
+    D: DelayNs,
+{
+    /// Read the EUI-48 MAC address from the EEPROM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Eui48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let eui48: Eui48 = eeprom.read_eui48()?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # assert_eq!(eui48, Eui48([0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]));
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn read_eui48(&mut self) -> Result<Eui48, Error<SPI::Error>> {
+        self.read_array(EUI48_MEMORY_ADDRESS).map(Eui48)
+    }
+
+    /// Read the EUI-48 MAC address from the EEPROM, and check that it is not
+    /// blank.
+    ///
+    /// An erased or unprogrammed part reads all `0xFF` or all `0x00`, which
+    /// must not be used as a MAC address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xFF; 6]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// assert_eq!(eeprom.validate_eui48(), Err(Error::BlankEui48));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    pub fn validate_eui48(&mut self) -> Result<Eui48, Error<SPI::Error>> {
+        let eui48: Eui48 = self.read_eui48()?;
+        if eui48.is_blank() {
+            Err(Error::BlankEui48)
+        } else {
+            Ok(eui48)
+        }
+    }
+
+    /// Read the EUI-48 MAC address from the EEPROM as a smoltcp
+    /// [`EthernetAddress`](smoltcp::wire::EthernetAddress).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
     /// #   hal::spi::Transaction::transaction_end(),
     /// # ]);
     /// use eeprom25aa02e48::Eeprom25aa02e48;
+    /// use smoltcp::wire::{EthernetAddress, HardwareAddress};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let mac: EthernetAddress = eeprom.read_ethernet_address()?;
+    /// let hardware_addr = HardwareAddress::Ethernet(mac);
+    /// # assert_eq!(mac, EthernetAddress([0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[cfg(feature = "smoltcp")]
+    pub fn read_ethernet_address(
+        &mut self,
+    ) -> Result<smoltcp::wire::EthernetAddress, Error<SPI::Error>> {
+        self.read_eui48().map(Into::into)
+    }
+
+    /// Read the EUI-48 MAC address from the EEPROM twice, and compare the
+    /// results.
+    ///
+    /// This guards against reading garbage from a marginal connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - Number of bytes per SPI transaction for the second
+    ///   read, using different transaction framing for the second read
+    ///   catches faults that only appear with longer transfers.
+    ///   `None` reads all 6 bytes in one transaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI48_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI48_MEMORY_ADDRESS + 3]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x78, 0x9A, 0xBC]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use core::num::NonZeroUsize;
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Eui48};
     ///
     /// let mut eeprom = Eeprom25aa02e48::new(spi);
-    /// let eui48: [u8; 6] = eeprom.read_eui48()?;
+    /// let eui48: Eui48 = eeprom.read_eui48_verified(NonZeroUsize::new(3))?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # assert_eq!(eui48, Eui48([0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]));
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn read_eui48_verified(
+        &mut self,
+        chunk_size: Option<NonZeroUsize>,
+    ) -> Result<Eui48, Error<SPI::Error>> {
+        let eui48: Eui48 = self.read_eui48()?;
+
+        let chunk_size: usize = chunk_size.map_or(EUI48_BYTES, NonZeroUsize::get);
+        let mut verify: [u8; EUI48_BYTES] = [0; EUI48_BYTES];
+        for (n, chunk) in verify.chunks_mut(chunk_size).enumerate() {
+            let address: u8 = EUI48_MEMORY_ADDRESS + (n * chunk_size) as u8;
+            self.read(address, chunk)?;
+        }
+
+        if eui48.0 == verify {
+            Ok(eui48)
+        } else {
+            Err(Error::Eui48Mismatch)
+        }
+    }
+}
+
+impl<SPI, D> Eeprom25aa02<SPI, E64, D>
+where
+    SPI: Transport,
+    D: DelayNs,
+{
+    /// Read the EUI-64 node address from a 25AA02E64 EEPROM.
+    ///
+    /// The 25AA02E64 is identical to the 25AA02E48, except that it stores
+    /// an EUI-64 node address at [`EUI64_MEMORY_ADDRESS`] instead of an EUI-48
+    /// MAC address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::{instruction, EUI64_MEMORY_ADDRESS};
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, EUI64_MEMORY_ADDRESS]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00, 0x04, 0xA3, 0xFF, 0xFE, 0x12, 0x34, 0x56]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e64;
+    ///
+    /// let mut eeprom = Eeprom25aa02e64::new(spi);
+    /// let eui64: [u8; 8] = eeprom.read_eui64()?;
     /// # let mut spi = eeprom.free(); spi.done();
-    /// # assert_eq!(eui48, [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
-    /// # Ok::<(), embedded_hal::spi::ErrorKind>(())
+    /// # assert_eq!(eui64, [0x00, 0x04, 0xA3, 0xFF, 0xFE, 0x12, 0x34, 0x56]);
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
     /// ```
-    pub fn read_eui48(&mut self) -> Result<[u8; EUI48_BYTES], SPI::Error> {
-        let mut eui48: [u8; EUI48_BYTES] = [0; EUI48_BYTES];
-        self.read(EUI48_MEMORY_ADDRESS, &mut eui48)?;
-        Ok(eui48)
+    pub fn read_eui64(&mut self) -> Result<[u8; EUI64_BYTES], Error<SPI::Error>> {
+        self.read_array(EUI64_MEMORY_ADDRESS)
     }
 }
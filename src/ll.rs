@@ -0,0 +1,116 @@
+//! Low-level access to the EEPROM instructions.
+//!
+//! This is an escape hatch for sequences the driver does not cover.
+//! Nothing here waits for write cycles, or checks the write enable latch, so
+//! the caller is responsible for following the datasheet.
+//!
+//! # Example
+//!
+//! ```
+//! # use eeprom25aa02e48::instruction;
+//! # use embedded_hal_mock::eh1 as hal;
+//! # let spi = hal::spi::Mock::new(&[
+//! #   hal::spi::Transaction::transaction_start(),
+//! #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+//! #   hal::spi::Transaction::transaction_end(),
+//! #   hal::spi::Transaction::transaction_start(),
+//! #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+//! #   hal::spi::Transaction::read_vec(vec![0x02]),
+//! #   hal::spi::Transaction::transaction_end(),
+//! #   hal::spi::Transaction::transaction_start(),
+//! #   hal::spi::Transaction::write_vec(vec![instruction::WRDI]),
+//! #   hal::spi::Transaction::transaction_end(),
+//! # ]);
+//! use eeprom25aa02e48::Eeprom25aa02e48;
+//!
+//! let mut eeprom = Eeprom25aa02e48::new(spi);
+//! let mut ll = eeprom.ll();
+//! ll.wren()?;
+//! assert_eq!(ll.rdsr_raw()?, 0x02);
+//! ll.wrdi()?;
+//! # let mut spi = eeprom.free(); spi.done();
+//! # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+//! ```
+
+use crate::{
+    instruction, Eeprom25aa02, Error, NoDelay, Transport, Variant, E48, HALF_DUPLEX_TURNAROUND_NS,
+};
+use embedded_hal::{delay::DelayNs, spi::Operation};
+
+/// Low-level handle to the EEPROM.
+///
+/// Created by [`Eeprom25aa02::ll`].
+pub struct LowLevel<'a, SPI, V = E48, D = NoDelay> {
+    pub(crate) eeprom: &'a mut Eeprom25aa02<SPI, V, D>,
+}
+
+impl<SPI, V, D> LowLevel<'_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Set the write enable latch.
+    pub fn wren(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.instruction(instruction::WREN, &[])
+    }
+
+    /// Reset the write enable latch.
+    pub fn wrdi(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.instruction(instruction::WRDI, &[])
+    }
+
+    /// Read the raw value of the STATUS register.
+    pub fn rdsr_raw(&mut self) -> Result<u8, Error<SPI::Error>> {
+        self.eeprom.read_status().map(u8::from)
+    }
+
+    /// Write the raw value of the STATUS register.
+    ///
+    /// The write enable latch must be set first, see [`wren`](Self::wren).
+    pub fn wrsr_raw(&mut self, value: u8) -> Result<(), Error<SPI::Error>> {
+        self.instruction(instruction::WRSR, &[value])
+    }
+
+    /// Send an instruction followed by a payload in a single transaction.
+    pub fn instruction(&mut self, opcode: u8, payload: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if payload.is_empty() {
+            self.eeprom
+                .bus
+                .transaction(&mut [Operation::Write(&[opcode])])?;
+        } else {
+            self.eeprom
+                .bus
+                .transaction(&mut [Operation::Write(&[opcode]), Operation::Write(payload)])?;
+        }
+        Ok(())
+    }
+
+    /// Send an instruction followed by a payload, then read the response in
+    /// a single transaction.
+    ///
+    /// In half-duplex mode the bus turnaround delay is inserted before the
+    /// response.
+    pub fn instruction_read(
+        &mut self,
+        opcode: u8,
+        payload: &[u8],
+        response: &mut [u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        if self.eeprom.bus.half_duplex() {
+            self.eeprom.bus.transaction(&mut [
+                Operation::Write(&[opcode]),
+                Operation::Write(payload),
+                Operation::DelayNs(HALF_DUPLEX_TURNAROUND_NS),
+                Operation::Read(response),
+            ])?;
+        } else {
+            self.eeprom.bus.transaction(&mut [
+                Operation::Write(&[opcode]),
+                Operation::Write(payload),
+                Operation::Read(response),
+            ])?;
+        }
+        Ok(())
+    }
+}
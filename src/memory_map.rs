@@ -0,0 +1,52 @@
+//! Named regions of the EEPROM array.
+//!
+//! The regions are inclusive ranges, so they can be passed directly to
+//! methods accepting a range such as [`Eeprom25aa02::bytes`].
+//!
+//! ```text
+//! 0x00 +-----------------+
+//!      | USER_AREA       |
+//! 0xC0 +-----------------+
+//!      | PROTECTED_BLOCK |
+//! 0xF8 |   EUI64_REGION  |
+//! 0xFA |   EUI48_REGION  |
+//! 0xFF +-----------------+
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! # use eeprom25aa02e48::instruction;
+//! # use embedded_hal_mock::eh1 as hal;
+//! # let spi = hal::spi::Mock::new(&[
+//! #   hal::spi::Transaction::transaction_start(),
+//! #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0xFA]),
+//! #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]),
+//! #   hal::spi::Transaction::transaction_end(),
+//! # ]);
+//! use eeprom25aa02e48::{memory_map::EUI48_REGION, Eeprom25aa02e48};
+//!
+//! let mut eeprom = Eeprom25aa02e48::new(spi);
+//! let mut buf: [u8; 6] = [0; 6];
+//! eeprom.read(*EUI48_REGION.start(), &mut buf)?;
+//! # assert_eq!(buf, [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
+//! # let mut spi = eeprom.free(); spi.done();
+//! # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+//! ```
+//!
+//! [`Eeprom25aa02::bytes`]: crate::Eeprom25aa02::bytes
+
+use crate::{EUI48_MEMORY_ADDRESS, EUI64_MEMORY_ADDRESS, PROTECTED_BLOCK_ADDRESS};
+use core::ops::RangeInclusive;
+
+/// Writable user area, the lower three quarters of the array.
+pub const USER_AREA: RangeInclusive<u8> = 0x00..=PROTECTED_BLOCK_ADDRESS - 1;
+
+/// Write-protected upper quarter of the array.
+pub const PROTECTED_BLOCK: RangeInclusive<u8> = PROTECTED_BLOCK_ADDRESS..=0xFF;
+
+/// EUI-48 node address on the 25AA02E48.
+pub const EUI48_REGION: RangeInclusive<u8> = EUI48_MEMORY_ADDRESS..=0xFF;
+
+/// EUI-64 node address on the 25AA02E64.
+pub const EUI64_REGION: RangeInclusive<u8> = EUI64_MEMORY_ADDRESS..=0xFF;
@@ -0,0 +1,272 @@
+use crate::{
+    crc::crc8, Eeprom25aa02, EepromRegion, Error, Transport, Variant, PAGE_SIZE,
+    PROTECTED_BLOCK_ADDRESS,
+};
+use embedded_hal::delay::DelayNs;
+
+/// EEPROM memory address of the partition table.
+///
+/// The table occupies the first page, partitions may not overlap it.
+pub const PARTITION_TABLE_ADDRESS: u8 = 0x00;
+
+/// Marks a page as containing a partition table.
+const MAGIC: u8 = 0xA5;
+
+/// Size of a serialized partition table entry in bytes.
+const ENTRY_SIZE: usize = 4;
+
+/// Index of the CRC-8 over the rest of the table page.
+const TABLE_CRC_INDEX: usize = PAGE_SIZE as usize - 1;
+
+/// Partition of the EEPROM array.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Partition {
+    /// Partition ID, unique within the table.
+    ///
+    /// Only this number is stored, partitions do not have names.
+    pub id: u8,
+    /// EEPROM memory address of the start of the partition.
+    pub offset: u8,
+    /// Length of the partition in bytes.
+    pub len: u8,
+}
+
+impl Partition {
+    fn end(&self) -> usize {
+        usize::from(self.offset) + usize::from(self.len)
+    }
+
+    fn to_bytes(self) -> [u8; ENTRY_SIZE] {
+        let bytes: [u8; 3] = [self.id, self.offset, self.len];
+        [bytes[0], bytes[1], bytes[2], crc8(&bytes)]
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if crc8(&bytes[..3]) == bytes[3] {
+            Some(Partition {
+                id: bytes[0],
+                offset: bytes[1],
+                len: bytes[2],
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Partition table stored in the first page of the array.
+///
+/// This allows multiple firmware components to share the array without
+/// address collisions.
+///
+/// The table holds up to [`PartitionTable::CAPACITY`] partitions, each
+/// stored with a one byte ID, offset, length, and CRC-8.
+/// The last byte of the page is a CRC-8 over the rest of the table,
+/// including the partition count.
+/// Partitions must lie between the table and the write-protected upper
+/// quarter of the array.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::{instruction, Partition, PartitionTable};
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let mut expected = PartitionTable::new();
+/// # expected.add(Partition { id: 1, offset: 0x10, len: 0x20 })?;
+/// # expected.add(Partition { id: 2, offset: 0x30, len: 0x40 })?;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x00]),
+/// #   hal::spi::Transaction::write_vec(expected.to_bytes().to_vec()),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::read_vec(expected.to_bytes().to_vec()),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+/// use embedded_hal::spi::ErrorKind;
+///
+/// let mut table = PartitionTable::new();
+/// table.add(Partition { id: 1, offset: 0x10, len: 0x20 })?;
+/// table.add(Partition { id: 2, offset: 0x30, len: 0x40 })?;
+///
+/// let overlapping: Result<(), Error<ErrorKind>> =
+///     table.add(Partition { id: 3, offset: 0x20, len: 0x20 });
+/// assert_eq!(overlapping, Err(Error::PartitionConflict));
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// eeprom.write_partition_table(&table)?;
+///
+/// let settings = eeprom.open_partition(2)?;
+/// assert_eq!(settings.start(), 0x30);
+/// assert_eq!(settings.len(), 0x40);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PartitionTable {
+    partitions: [Partition; PartitionTable::CAPACITY],
+    count: u8,
+}
+
+impl PartitionTable {
+    /// Maximum number of partitions in the table.
+    pub const CAPACITY: usize = 3;
+
+    /// Creates an empty partition table.
+    #[inline]
+    pub const fn new() -> Self {
+        PartitionTable {
+            partitions: [Partition {
+                id: 0,
+                offset: 0,
+                len: 0,
+            }; Self::CAPACITY],
+            count: 0,
+        }
+    }
+
+    /// Add a partition to the table.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::DataTooLong`] if the table is full.
+    /// * [`Error::OutOfBounds`] if the partition overlaps the table or the
+    ///   write-protected upper quarter of the array.
+    /// * [`Error::PartitionConflict`] if the partition overlaps another
+    ///   partition, or reuses its ID.
+    pub fn add<E>(&mut self, partition: Partition) -> Result<(), Error<E>> {
+        if usize::from(self.count) == Self::CAPACITY {
+            Err(Error::DataTooLong)
+        } else if partition.offset < PARTITION_TABLE_ADDRESS + PAGE_SIZE
+            || partition.end() > usize::from(PROTECTED_BLOCK_ADDRESS)
+        {
+            Err(Error::OutOfBounds)
+        } else if self.iter().any(|p| {
+            p.id == partition.id
+                || (usize::from(partition.offset) < p.end()
+                    && usize::from(p.offset) < partition.end())
+        }) {
+            Err(Error::PartitionConflict)
+        } else {
+            self.partitions[usize::from(self.count)] = partition;
+            self.count += 1;
+            Ok(())
+        }
+    }
+
+    /// Returns the partition with the given ID.
+    pub fn get(&self, id: u8) -> Option<Partition> {
+        self.iter().find(|p| p.id == id)
+    }
+
+    /// Returns an iterator over the partitions.
+    pub fn iter(&self) -> impl Iterator<Item = Partition> + '_ {
+        self.partitions[..usize::from(self.count)].iter().copied()
+    }
+
+    /// Serialize the table into a page.
+    ///
+    /// Unused entries are filled with `0xFF`, and the last byte is a CRC-8
+    /// over the rest of the page.
+    pub fn to_bytes(&self) -> [u8; PAGE_SIZE as usize] {
+        let mut page: [u8; PAGE_SIZE as usize] = [0xFF; PAGE_SIZE as usize];
+        page[0] = MAGIC;
+        page[1] = self.count;
+        for (partition, entry) in self.iter().zip(page[2..].chunks_exact_mut(ENTRY_SIZE)) {
+            entry.copy_from_slice(&partition.to_bytes());
+        }
+        page[TABLE_CRC_INDEX] = crc8(&page[..TABLE_CRC_INDEX]);
+        page
+    }
+
+    /// Deserialize a table from a page.
+    ///
+    /// Returns `None` if the page does not contain a valid table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use eeprom25aa02e48::{Partition, PartitionTable};
+    ///
+    /// let mut table = PartitionTable::new();
+    /// table.add::<()>(Partition { id: 1, offset: 0x10, len: 0x20 })?;
+    ///
+    /// let mut page: [u8; 16] = table.to_bytes();
+    /// assert_eq!(PartitionTable::from_bytes(&page), Some(table));
+    ///
+    /// // a corrupted partition count is detected
+    /// page[1] = 0;
+    /// assert_eq!(PartitionTable::from_bytes(&page), None);
+    /// # Ok::<(), eeprom25aa02e48::Error<()>>(())
+    /// ```
+    pub fn from_bytes(page: &[u8; PAGE_SIZE as usize]) -> Option<Self> {
+        if page[0] != MAGIC
+            || usize::from(page[1]) > Self::CAPACITY
+            || crc8(&page[..TABLE_CRC_INDEX]) != page[TABLE_CRC_INDEX]
+        {
+            return None;
+        }
+        let mut table: PartitionTable = PartitionTable::new();
+        for entry in page[2..].chunks_exact(ENTRY_SIZE).take(page[1].into()) {
+            table.add::<()>(Partition::from_bytes(entry)?).ok()?;
+        }
+        Some(table)
+    }
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Read the partition table.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Corrupted`] if the first page does not contain a
+    /// valid partition table.
+    pub fn read_partition_table(&mut self) -> Result<PartitionTable, Error<SPI::Error>> {
+        let page: [u8; PAGE_SIZE as usize] = self.read_array(PARTITION_TABLE_ADDRESS)?;
+        PartitionTable::from_bytes(&page).ok_or(Error::Corrupted)
+    }
+
+    /// Write the partition table to the first page.
+    ///
+    /// Waits for the write cycle, see [write cycles](crate#write-cycles).
+    pub fn write_partition_table(
+        &mut self,
+        table: &PartitionTable,
+    ) -> Result<(), Error<SPI::Error>> {
+        self.write(PARTITION_TABLE_ADDRESS, &table.to_bytes())
+    }
+
+    /// Open a partition from the partition table.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Corrupted`] if the partition table is not valid.
+    /// * [`Error::PartitionNotFound`] if the table has no partition with the
+    ///   given ID.
+    pub fn open_partition(
+        &mut self,
+        id: u8,
+    ) -> Result<EepromRegion<'_, SPI, V, D>, Error<SPI::Error>> {
+        let partition: Partition = self
+            .read_partition_table()?
+            .get(id)
+            .ok_or(Error::PartitionNotFound)?;
+        self.region(partition.offset, partition.len.into())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE};
+use embedded_hal::delay::DelayNs;
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Read a plain old data type from an EEPROM memory address.
+    ///
+    /// The value is read in the in-memory representation of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x20]),
+    /// #   hal::spi::Transaction::write_vec(vec![0xE8, 0x03, 0x05, 0x01]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x20]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xE8, 0x03, 0x05, 0x01]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    /// use zerocopy::{FromBytes, Immutable, IntoBytes};
+    ///
+    /// #[derive(Debug, PartialEq, FromBytes, IntoBytes, Immutable)]
+    /// #[repr(C)]
+    /// struct Settings {
+    ///     timeout_ms: u16,
+    ///     retries: u8,
+    ///     enabled: u8,
+    /// }
+    ///
+    /// let settings = Settings { timeout_ms: 1000, retries: 5, enabled: 1 };
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_as(0x20, &settings)?;
+    /// assert_eq!(eeprom.read_as::<Settings>(0x20)?, settings);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn read_as<T: FromBytes + IntoBytes>(
+        &mut self,
+        address: u8,
+    ) -> Result<T, Error<SPI::Error>> {
+        const { assert!(size_of::<T>() <= ARRAY_SIZE) };
+        let mut value: T = T::new_zeroed();
+        self.read(address, value.as_mut_bytes())?;
+        Ok(value)
+    }
+
+    /// Write a plain old data type to an EEPROM memory address.
+    ///
+    /// The value is written in the in-memory representation of `T`.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// See [`Eeprom25aa02::read_as`] for an example.
+    pub fn write_as<T: IntoBytes + Immutable>(
+        &mut self,
+        address: u8,
+        value: &T,
+    ) -> Result<(), Error<SPI::Error>> {
+        self.write(address, value.as_bytes())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::EUI64_MEMORY_ADDRESS;
+
+/// Policy consulted by the driver before every write to the array.
+///
+/// This allows applications to centrally enforce which regions the firmware
+/// may modify.
+/// Writes denied by the policy return [`Error::WriteDenied`](crate::Error::WriteDenied)
+/// without touching the bus.
+///
+/// The policy is not consulted by the [`ll`](crate::ll) module.
+///
+/// # Example
+///
+/// ```
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, Error, WritePolicy};
+///
+/// /// Only allow writes to the first 64 bytes.
+/// struct Settings;
+///
+/// impl WritePolicy for Settings {
+///     fn allows(&self, address: u8, len: usize) -> bool {
+///         usize::from(address) + len <= 0x40
+///     }
+/// }
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// eeprom.set_write_policy(&Settings);
+/// assert_eq!(eeprom.write(0x3C, &[0; 8]), Err(Error::WriteDenied));
+/// # let mut spi = eeprom.free(); spi.done();
+/// ```
+pub trait WritePolicy: Sync {
+    /// Returns `true` if `len` bytes may be written starting at `address`.
+    fn allows(&self, address: u8, len: usize) -> bool;
+}
+
+/// Default write policy.
+///
+/// Allows writes to the user area, and denies writes overlapping the node
+/// address block from [`EUI64_MEMORY_ADDRESS`] to the end of the array.
+/// This covers the EUI-48 and the EUI-64.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DefaultWritePolicy;
+
+impl WritePolicy for DefaultWritePolicy {
+    #[inline]
+    fn allows(&self, address: u8, len: usize) -> bool {
+        usize::from(address) + len <= usize::from(EUI64_MEMORY_ADDRESS)
+    }
+}
+
+/// Write policy that allows every write.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AllowAll;
+
+impl WritePolicy for AllowAll {
+    #[inline]
+    fn allows(&self, _address: u8, _len: usize) -> bool {
+        true
+    }
+}
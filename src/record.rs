@@ -0,0 +1,231 @@
+use crate::{crc::crc8, Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE};
+use embedded_hal::delay::DelayNs;
+
+/// Struct stored at a fixed EEPROM address.
+///
+/// Records are stored followed by a CRC-8, which is checked on load.
+///
+/// This is usually derived with `#[derive(EepromRecord)]`, which requires
+/// the `derive` feature.
+/// Every field must implement [`FieldValue`](crate::FieldValue), and the
+/// address is set with `#[eeprom(address = ...)]`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x20]),
+/// #   hal::spi::Transaction::write_vec(vec![0xE8, 0x03, 0x01, 0x5B]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x20]),
+/// #   hal::spi::Transaction::read_vec(vec![0xE8, 0x03, 0x01]),
+/// #   hal::spi::Transaction::read_vec(vec![0x5B]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, EepromRecord};
+///
+/// #[derive(Debug, PartialEq, EepromRecord)]
+/// #[eeprom(address = 0x20)]
+/// struct Settings {
+///     timeout_ms: u16,
+///     enabled: bool,
+/// }
+///
+/// let settings = Settings { timeout_ms: 1000, enabled: true };
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// eeprom.store_record(&settings)?;
+/// assert_eq!(eeprom.load_record::<Settings>()?, settings);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # }
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub trait EepromRecord: Sized {
+    /// EEPROM memory address of the record.
+    const ADDRESS: u8;
+    /// Size of the serialized record in bytes, excluding the CRC.
+    const SIZE: usize;
+
+    /// Serialize the record into `buf`, which is [`SIZE`](Self::SIZE) bytes.
+    fn write_bytes(&self, buf: &mut [u8]);
+
+    /// Deserialize the record from `buf`, which is [`SIZE`](Self::SIZE)
+    /// bytes.
+    fn read_bytes(buf: &[u8]) -> Self;
+}
+
+/// Call `f` with a zeroed buffer of `len` bytes.
+///
+/// An array cannot be sized from a generic constant, so the buffer is
+/// rounded up to the next power of two, and each size gets its own stack
+/// frame.
+#[inline(always)]
+fn with_buffer<R>(len: usize, f: impl FnOnce(&mut [u8]) -> R) -> R {
+    #[inline(never)]
+    fn sized<const N: usize, R>(len: usize, f: impl FnOnce(&mut [u8]) -> R) -> R {
+        f(&mut [0; N][..len])
+    }
+
+    match len {
+        0..=16 => sized::<16, R>(len, f),
+        17..=32 => sized::<32, R>(len, f),
+        33..=64 => sized::<64, R>(len, f),
+        65..=128 => sized::<128, R>(len, f),
+        _ => sized::<ARRAY_SIZE, R>(len, f),
+    }
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Load a record.
+    ///
+    /// See [`EepromRecord`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Corrupted`] if the CRC does not match.
+    pub fn load_record<T: EepromRecord>(&mut self) -> Result<T, Error<SPI::Error>> {
+        const { assert!(T::ADDRESS as usize + T::SIZE < ARRAY_SIZE) };
+        with_buffer(T::SIZE, |buf| {
+            let mut crc: [u8; 1] = [0];
+            self.read_split(T::ADDRESS, buf, &mut crc)?;
+            if crc8(buf) == crc[0] {
+                Ok(T::read_bytes(buf))
+            } else {
+                Err(Error::Corrupted)
+            }
+        })
+    }
+
+    /// Store a record.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// See [`EepromRecord`] for an example.
+    pub fn store_record<T: EepromRecord>(&mut self, record: &T) -> Result<(), Error<SPI::Error>> {
+        const { assert!(T::ADDRESS as usize + T::SIZE < ARRAY_SIZE) };
+        with_buffer(T::SIZE, |buf| {
+            record.write_bytes(buf);
+            let crc: u8 = crc8(buf);
+            self.write_parts(T::ADDRESS, &[buf, &[crc]])
+        })
+    }
+
+    /// Write data followed by its CRC-8.
+    ///
+    /// The data and CRC may span multiple pages.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if the data and CRC are longer than the
+    /// array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x30]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x12, 0x34, 0x53]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x30]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x53]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x30]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12, 0x35]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x53]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.write_record(0x30, &[0x12, 0x34])?;
+    ///
+    /// let mut buf: [u8; 2] = [0; 2];
+    /// eeprom.read_record(0x30, &mut buf)?;
+    /// assert_eq!(buf, [0x12, 0x34]);
+    ///
+    /// // a bit flipped
+    /// assert_eq!(eeprom.read_record(0x30, &mut buf), Err(Error::Corrupted));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn write_record(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if data.len() >= ARRAY_SIZE {
+            return Err(Error::DataTooLong);
+        }
+        self.write_parts(address, &[data, &[crc8(data)]])
+    }
+
+    /// Read data written with [`Eeprom25aa02::write_record`], filling `buf`.
+    ///
+    /// See [`Eeprom25aa02::write_record`] for an example.
+    ///
+    /// Data that reads as all `0x00`, for example from a missing device with
+    /// MISO pulled low, fails the CRC.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x30]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00; 2]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let mut buf: [u8; 2] = [0; 2];
+    /// assert_eq!(eeprom.read_record(0x30, &mut buf), Err(Error::Corrupted));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::DataTooLong`] if `buf` and the CRC are longer than the
+    ///   array.
+    /// * [`Error::Corrupted`] if the CRC does not match.
+    pub fn read_record(&mut self, address: u8, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        if buf.len() >= ARRAY_SIZE {
+            return Err(Error::DataTooLong);
+        }
+        let mut crc: [u8; 1] = [0];
+        self.read_split(address, buf, &mut crc)?;
+        if crc8(buf) == crc[0] {
+            Ok(())
+        } else {
+            Err(Error::Corrupted)
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{
+    crc::{crc8, crc8_update},
+    EepromRegion, Error, NoDelay, Transport, Variant, E48,
+};
+use embedded_hal::delay::DelayNs;
+
+/// Length byte of erased memory, marking the end of the log.
+const END: u8 = 0xFF;
+
+/// Size of the length and CRC of a record in bytes.
+const OVERHEAD: usize = 2;
+
+/// Append-only log of records in a region of the EEPROM.
+///
+/// Each record is stored as a length, the data, and a CRC-8.
+/// Reading stops at the first corrupt record, such as a record torn by
+/// power loss, and the next append overwrites it.
+/// When the region is full appends fail until the log is cleared.
+///
+/// The region must be filled with `0xFF` before first use, see
+/// [`RecordLog::clear`].
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # fn read(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+/// #     hal::spi::Transaction::read_vec(data),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # fn read_split(address: u8, data: Vec<u8>, crc: u8) -> Vec<hal::spi::Transaction<u8>> {
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+/// #     hal::spi::Transaction::read_vec(data),
+/// #     hal::spi::Transaction::read_vec(vec![crc]),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # let mut expectations = vec![];
+/// # expectations.extend(read(0x60, vec![0x02]));
+/// # expectations.extend(read(0x61, vec![0xAB, 0xCD]));
+/// # expectations.extend(read(0x63, vec![0x8B]));
+/// # expectations.extend(read(0x64, vec![0xFF]));
+/// # expectations.extend([
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x64]),
+/// #   hal::spi::Transaction::write_vec(vec![0x01, 0x42, 0x24]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// # expectations.extend(read(0x60, vec![0x02]));
+/// # expectations.extend(read_split(0x61, vec![0xAB, 0xCD], 0x8B));
+/// # expectations.extend(read(0x64, vec![0x01]));
+/// # expectations.extend(read_split(0x65, vec![0x42], 0x24));
+/// # expectations.extend(read(0x67, vec![0xFF]));
+/// # let spi = hal::spi::Mock::new(&expectations);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, RecordLog};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// let mut log = RecordLog::new(eeprom.region(0x60, 16)?);
+///
+/// log.append(&[0x42])?;
+///
+/// let mut records = log.records();
+/// let mut buf: [u8; 8] = [0; 8];
+/// assert_eq!(records.read_next(&mut buf)?, Some([0xAB, 0xCD].as_slice()));
+/// assert_eq!(records.read_next(&mut buf)?, Some([0x42].as_slice()));
+/// assert_eq!(records.read_next(&mut buf)?, None);
+/// # drop(records);
+/// # drop(log);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct RecordLog<'a, SPI, V = E48, D = NoDelay> {
+    region: EepromRegion<'a, SPI, V, D>,
+}
+
+impl<'a, SPI, V, D> RecordLog<'a, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Maximum length of a record in bytes.
+    pub const MAX_RECORD_LEN: usize = END as usize - 1;
+
+    /// Creates a record log in a region.
+    #[inline]
+    pub fn new(region: EepromRegion<'a, SPI, V, D>) -> Self {
+        RecordLog { region }
+    }
+
+    /// Free the underlying region.
+    #[inline]
+    pub fn free(self) -> EepromRegion<'a, SPI, V, D> {
+        self.region
+    }
+
+    /// Erase every record, filling the region with `0xFF`.
+    pub fn clear(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.region.fill(0xFF)
+    }
+
+    /// Append a record after the last valid record.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if the record is longer than
+    /// [`RecordLog::MAX_RECORD_LEN`], or does not fit in the remainder of
+    /// the region.
+    pub fn append(&mut self, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if data.len() > Self::MAX_RECORD_LEN {
+            return Err(Error::DataTooLong);
+        }
+        let end: usize = self.end()?;
+        if end + OVERHEAD + data.len() > self.region.len() {
+            return Err(Error::DataTooLong);
+        }
+
+        // checked against MAX_RECORD_LEN
+        let len: u8 = data.len() as u8;
+        let crc: u8 = crc8_update(crc8(&[len]), data);
+        // within the region
+        let address: u8 = self.region.start() + end as u8;
+        self.region
+            .eeprom
+            .write_parts(address, &[&[len], data, &[crc]])
+    }
+
+    /// Read the records one at a time, up to the first corrupt record.
+    ///
+    /// See [`RecordLog`] for an example.
+    #[inline]
+    pub fn records(&mut self) -> Records<'_, 'a, SPI, V, D> {
+        Records {
+            log: self,
+            offset: 0,
+            done: false,
+        }
+    }
+
+    /// Returns the length of the record at `offset`, or `None` at the end of
+    /// the log.
+    fn record_len(&mut self, offset: usize) -> Result<Option<usize>, Error<SPI::Error>> {
+        if offset >= self.region.len() {
+            return Ok(None);
+        }
+        let mut len: [u8; 1] = [0];
+        self.region.read(offset, &mut len)?;
+        let len: usize = len[0].into();
+        if len == usize::from(END) || offset + OVERHEAD + len > self.region.len() {
+            Ok(None)
+        } else {
+            Ok(Some(len))
+        }
+    }
+
+    /// Offset of the end of the last valid record.
+    fn end(&mut self) -> Result<usize, Error<SPI::Error>> {
+        let mut offset: usize = 0;
+        while let Some(len) = self.record_len(offset)? {
+            let data: usize = usize::from(self.region.start()) + offset + 1;
+            let crc: u8 = self.region.eeprom.fold_range(
+                data..data + len,
+                // less than END
+                crc8(&[len as u8]),
+                crc8_update,
+            )?;
+            let mut stored: [u8; 1] = [0];
+            self.region.read(offset + 1 + len, &mut stored)?;
+            if crc != stored[0] {
+                break;
+            }
+            offset += OVERHEAD + len;
+        }
+        Ok(offset)
+    }
+}
+
+/// Cursor over the records of a [`RecordLog`].
+///
+/// Created by [`RecordLog::records`].
+pub struct Records<'s, 'a, SPI, V = E48, D = NoDelay> {
+    log: &'s mut RecordLog<'a, SPI, V, D>,
+    offset: usize,
+    done: bool,
+}
+
+impl<SPI, V, D> Records<'_, '_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Read the next record into `buf`.
+    ///
+    /// Returns the record, or `None` after the last valid record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if `buf` is shorter than the record.
+    pub fn read_next<'b>(
+        &mut self,
+        buf: &'b mut [u8],
+    ) -> Result<Option<&'b [u8]>, Error<SPI::Error>> {
+        if self.done {
+            return Ok(None);
+        }
+        let Some(len) = self.log.record_len(self.offset)? else {
+            self.done = true;
+            return Ok(None);
+        };
+        let data: &'b mut [u8] = buf.get_mut(..len).ok_or(Error::DataTooLong)?;
+        let mut crc: [u8; 1] = [0];
+        // within the region
+        let address: u8 = self.log.region.start() + (self.offset + 1) as u8;
+        self.log.region.eeprom.read_split(address, data, &mut crc)?;
+        // less than END
+        if crc8_update(crc8(&[len as u8]), data) != crc[0] {
+            self.done = true;
+            return Ok(None);
+        }
+        self.offset += OVERHEAD + len;
+        Ok(Some(data))
+    }
+}
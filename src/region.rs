@@ -0,0 +1,175 @@
+use crate::{Eeprom25aa02, Error, NoDelay, Transport, Variant, E48};
+use embedded_hal::delay::DelayNs;
+
+/// Window onto a region of the EEPROM.
+///
+/// Created by [`Eeprom25aa02::region`].
+///
+/// Addresses are relative to the start of the region, and accesses outside
+/// of the region return [`Error::OutOfBounds`].
+/// This allows subsystems to share an EEPROM without touching each other's
+/// areas, or legacy layouts that assumed a different base address to be
+/// used without changing every address constant.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x44]),
+/// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34, 0x56, 0x78]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// let mut legacy = eeprom.region(0x40, 0x20)?;
+///
+/// let mut buf: [u8; 4] = [0; 4];
+/// legacy.read(0x04, &mut buf)?;
+/// assert_eq!(buf, [0x12, 0x34, 0x56, 0x78]);
+///
+/// assert_eq!(legacy.read(0x1E, &mut buf), Err(Error::OutOfBounds));
+/// assert_eq!(legacy.read(usize::MAX, &mut buf), Err(Error::OutOfBounds));
+/// # drop(legacy);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct EepromRegion<'a, SPI, V = E48, D = NoDelay> {
+    pub(crate) eeprom: &'a mut Eeprom25aa02<SPI, V, D>,
+    pub(crate) start: u8,
+    pub(crate) len: usize,
+}
+
+impl<SPI, V, D> EepromRegion<'_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Start address of the region.
+    #[inline]
+    pub fn start(&self) -> u8 {
+        self.start
+    }
+
+    /// Length of the region in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the region has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Create a window onto part of the region.
+    ///
+    /// `offset` is relative to the start of the region, so a legacy layout
+    /// can be applied inside a partition returned by
+    /// [`Eeprom25aa02::open_partition`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x49]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x12]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let mut partition = eeprom.region(0x40, 0x20)?;
+    /// let mut legacy = partition.subregion(0x08, 0x08)?;
+    ///
+    /// let mut buf: [u8; 1] = [0];
+    /// legacy.read(0x01, &mut buf)?;
+    /// assert_eq!(buf, [0x12]);
+    ///
+    /// assert_eq!(legacy.read(0x08, &mut buf), Err(Error::OutOfBounds));
+    /// assert_eq!(partition.subregion(0x18, 0x10).err(), Some(Error::OutOfBounds));
+    /// # drop(partition);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The window may not extend past the end of the region, this returns
+    /// [`Error::OutOfBounds`].
+    pub fn subregion(
+        &mut self,
+        offset: usize,
+        len: usize,
+    ) -> Result<EepromRegion<'_, SPI, V, D>, Error<SPI::Error>> {
+        let start: u8 = self.translate(offset, len)?;
+        Ok(EepromRegion {
+            eeprom: self.eeprom,
+            start,
+            len,
+        })
+    }
+
+    /// Translate a relative address to an EEPROM address.
+    fn translate(&self, address: usize, len: usize) -> Result<u8, Error<SPI::Error>> {
+        match address.checked_add(len) {
+            Some(end) if end <= self.len => {
+                u8::try_from(usize::from(self.start) + address).map_err(|_| Error::OutOfBounds)
+            }
+            _ => Err(Error::OutOfBounds),
+        }
+    }
+
+    /// Read from the region.
+    ///
+    /// See [`Eeprom25aa02::read`].
+    pub fn read(&mut self, address: usize, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        let address: u8 = self.translate(address, buf.len())?;
+        self.eeprom.read(address, buf)
+    }
+
+    /// Writes up to a page of data to the region.
+    ///
+    /// The address must be page aligned in the EEPROM, which is also page
+    /// aligned in the region when the region starts on a page boundary.
+    ///
+    /// See [`Eeprom25aa02::write_page`].
+    pub fn write_page(&mut self, address: usize, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        let address: u8 = self.translate(address, data.len())?;
+        self.eeprom.write_page(address, data)
+    }
+
+    /// Write to the region.
+    ///
+    /// See [`Eeprom25aa02::write`].
+    pub fn write(&mut self, address: usize, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        let address: u8 = self.translate(address, data.len())?;
+        self.eeprom.write(address, data)
+    }
+
+    /// Fill the region with a constant value.
+    ///
+    /// See [`Eeprom25aa02::fill`].
+    pub fn fill(&mut self, value: u8) -> Result<(), Error<SPI::Error>> {
+        self.eeprom.fill(self.start, self.len, value)
+    }
+
+    /// Fill part of the region with a constant value.
+    pub(crate) fn fill_range(
+        &mut self,
+        address: usize,
+        len: usize,
+        value: u8,
+    ) -> Result<(), Error<SPI::Error>> {
+        let address: u8 = self.translate(address, len)?;
+        self.eeprom.fill(address, len, value)
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{
+    crc::{crc8, crc8_update},
+    EepromRegion, Error, NoDelay, Transport, Variant, E48,
+};
+use embedded_hal::delay::DelayNs;
+
+/// Size of the sequence number, length, and CRC of a slot in bytes.
+const OVERHEAD: usize = 3;
+
+/// Circular log of small entries in a region of the EEPROM.
+///
+/// The region is divided into slots of up to `entry_size` bytes of data,
+/// each stored with a sequence number, a length, and a CRC-8.
+/// When the log is full the oldest entry is overwritten.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let entry: Vec<u8> = vec![0x00, 0x02, 0x01, 0x02, 0xFF, 0xFF, 0xFF, 0xBF];
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x80]),
+/// #   hal::spi::Transaction::read_vec(vec![0xFF; 8]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x88]),
+/// #   hal::spi::Transaction::read_vec(vec![0xFF; 8]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x80]),
+/// #   hal::spi::Transaction::write_vec(entry.clone()),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x80]),
+/// #   hal::spi::Transaction::read_vec(entry.clone()),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x88]),
+/// #   hal::spi::Transaction::read_vec(vec![0xFF; 8]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x88]),
+/// #   hal::spi::Transaction::read_vec(vec![0xFF, 0xFF]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x80]),
+/// #   hal::spi::Transaction::read_vec(entry[..2].to_vec()),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x82]),
+/// #   hal::spi::Transaction::read_vec(entry[2..7].to_vec()),
+/// #   hal::spi::Transaction::read_vec(entry[7..].to_vec()),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, RingLog};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// let mut log = RingLog::new(eeprom.region(0x80, 16)?, 5)?;
+/// assert_eq!(log.capacity(), 2);
+///
+/// log.append(&[0x01, 0x02])?;
+///
+/// let mut entries = log.entries()?;
+/// let mut buf: [u8; 5] = [0; 5];
+/// while let Some(entry) = entries.read_next(&mut buf)? {
+///     assert_eq!(entry, [0x01, 0x02]);
+/// }
+/// # drop(entries);
+/// # drop(log);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct RingLog<'a, SPI, V = E48, D = NoDelay> {
+    region: EepromRegion<'a, SPI, V, D>,
+    entry_size: usize,
+}
+
+impl<'a, SPI, V, D> RingLog<'a, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Creates a log in a region, holding entries of up to `entry_size`
+    /// bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if the region cannot hold at least one
+    /// entry.
+    pub fn new(
+        region: EepromRegion<'a, SPI, V, D>,
+        entry_size: usize,
+    ) -> Result<Self, Error<SPI::Error>> {
+        if entry_size + OVERHEAD > region.len() || entry_size > usize::from(u8::MAX) {
+            Err(Error::DataTooLong)
+        } else {
+            Ok(RingLog { region, entry_size })
+        }
+    }
+
+    /// Free the underlying region.
+    #[inline]
+    pub fn free(self) -> EepromRegion<'a, SPI, V, D> {
+        self.region
+    }
+
+    /// Number of entries the log holds before the oldest is overwritten.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.region.len() / self.slot_size()
+    }
+
+    #[inline]
+    fn slot_size(&self) -> usize {
+        self.entry_size + OVERHEAD
+    }
+
+    /// Erase every entry, filling the region with `0xFF`.
+    pub fn clear(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.region.fill(0xFF)
+    }
+
+    /// Append an entry, overwriting the oldest entry if the log is full.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if `data` is longer than the entry
+    /// size.
+    pub fn append(&mut self, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if data.len() > self.entry_size {
+            return Err(Error::DataTooLong);
+        }
+        let (address, sequence): (u8, u8) = match self.newest()? {
+            Some((index, sequence)) => {
+                let next: usize = (index + 1) % self.capacity();
+                (self.slot_address(next), sequence.wrapping_add(1))
+            }
+            None => (self.region.start(), 0),
+        };
+        // checked against the entry size, which fits in a u8
+        let len: u8 = data.len() as u8;
+        self.region
+            .eeprom
+            .write_slot(address, self.slot_size(), sequence, &[&[len], data])
+    }
+
+    /// Read the entries one at a time, from oldest to newest.
+    ///
+    /// See [`RingLog`] for an example.
+    pub fn entries(&mut self) -> Result<RingEntries<'_, 'a, SPI, V, D>, Error<SPI::Error>> {
+        let index: usize = self.newest()?.map_or(0, |(index, _)| index + 1);
+        Ok(RingEntries {
+            remaining: self.capacity(),
+            log: self,
+            index,
+        })
+    }
+
+    /// EEPROM address of a slot.
+    #[inline]
+    fn slot_address(&self, index: usize) -> u8 {
+        // the slots are within the region
+        self.region.start() + (index * self.slot_size()) as u8
+    }
+
+    /// Returns the index and sequence number of the newest slot.
+    fn newest(&mut self) -> Result<Option<(usize, u8)>, Error<SPI::Error>> {
+        let start: u8 = self.region.start();
+        let slot_size: usize = self.slot_size();
+        // the slots are within the region
+        let addresses = (0..self.capacity()).map(|index| start + (index * slot_size) as u8);
+        Ok(self
+            .region
+            .eeprom
+            .newest_slot(addresses, slot_size)?
+            .map(|(address, sequence)| (usize::from(address - start) / slot_size, sequence)))
+    }
+}
+
+/// Cursor over the entries of a [`RingLog`], from oldest to newest.
+///
+/// Created by [`RingLog::entries`].
+pub struct RingEntries<'s, 'a, SPI, V = E48, D = NoDelay> {
+    log: &'s mut RingLog<'a, SPI, V, D>,
+    index: usize,
+    remaining: usize,
+}
+
+impl<SPI, V, D> RingEntries<'_, '_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Read the next entry into `buf`, skipping empty and corrupted slots.
+    ///
+    /// Returns the entry, or `None` after the newest entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if `buf` is shorter than the entry
+    /// size.
+    pub fn read_next<'b>(
+        &mut self,
+        buf: &'b mut [u8],
+    ) -> Result<Option<&'b [u8]>, Error<SPI::Error>> {
+        let entry_size: usize = self.log.entry_size;
+        if buf.len() < entry_size {
+            return Err(Error::DataTooLong);
+        }
+        while self.remaining > 0 {
+            let address: u8 = self.log.slot_address(self.index % self.log.capacity());
+            self.index += 1;
+            self.remaining -= 1;
+
+            let mut header: [u8; 2] = [0; 2];
+            self.log.region.eeprom.read(address, &mut header)?;
+            let len: usize = header[1].into();
+            if len > entry_size {
+                continue;
+            }
+            let mut crc: [u8; 1] = [0];
+            self.log
+                .region
+                .eeprom
+                .read_split(address + 2, &mut buf[..entry_size], &mut crc)?;
+            if crc8_update(crc8(&header), &buf[..entry_size]) == crc[0] {
+                return Ok(Some(&buf[..len]));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{Eeprom25aa02, Error, Transport, TripleSlots, Variant, Vote};
+use embedded_hal::delay::DelayNs;
+
+/// Statistics from a [`Eeprom25aa02::scrub`] pass.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ScrubStats {
+    corrected_bits: u32,
+    repaired: u32,
+    uncorrectable: u32,
+}
+
+impl ScrubStats {
+    /// Number of bit errors corrected in ECC regions.
+    #[inline]
+    pub fn corrected_bits(&self) -> u32 {
+        self.corrected_bits
+    }
+
+    /// Number of ECC regions and triple slots that were rewritten.
+    #[inline]
+    pub fn repaired(&self) -> u32 {
+        self.repaired
+    }
+
+    /// Number of ECC regions with an uncorrectable error.
+    #[inline]
+    pub fn uncorrectable(&self) -> u32 {
+        self.uncorrectable
+    }
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Correct errors in ECC regions and triple slots before they
+    /// accumulate.
+    ///
+    /// This is intended to be called periodically from a low priority task.
+    /// Every region is passed to [`Eeprom25aa02::scrub_ecc`] and every set
+    /// of slots to [`Eeprom25aa02::scrub_triple`].
+    /// An uncorrectable ECC region is counted in
+    /// [`ScrubStats::uncorrectable`] and the pass continues.
+    ///
+    /// # Arguments
+    ///
+    /// * `ecc` - Addresses and data lengths of regions written with
+    ///   [`Eeprom25aa02::write_ecc`].
+    /// * `triples` - Slots written with [`Eeprom25aa02::write_triple`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # fn read(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+    /// #     hal::spi::Transaction::read_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # fn write(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # let mut expectations = vec![];
+    /// # expectations.extend(read(0x40, vec![0x7A, 0xA5]));
+    /// # expectations.extend(write(0x40, vec![0x5A, 0xA5]));
+    /// # expectations.extend(read(0x48, vec![0x5C, 0xA5]));
+    /// # expectations.extend(read(0x50, vec![0xCA, 0x1B]));
+    /// # expectations.extend(read(0x58, vec![0xCA, 0x1B]));
+    /// # expectations.extend(read(0x60, vec![0xCA, 0x1B]));
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, ScrubStats, TripleSlots};
+    ///
+    /// const CALIBRATION_VALID: TripleSlots = TripleSlots::new([0x50, 0x58, 0x60], 2);
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let stats: ScrubStats = eeprom.scrub(&[(0x40, 1), (0x48, 1)], &[CALIBRATION_VALID])?;
+    /// assert_eq!(stats.corrected_bits(), 1);
+    /// assert_eq!(stats.repaired(), 1);
+    /// assert_eq!(stats.uncorrectable(), 1);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn scrub(
+        &mut self,
+        ecc: &[(u8, usize)],
+        triples: &[TripleSlots],
+    ) -> Result<ScrubStats, Error<SPI::Error>> {
+        let mut stats: ScrubStats = ScrubStats::default();
+        for &(address, len) in ecc {
+            match self.scrub_ecc(address, len) {
+                Ok(0) => (),
+                Ok(corrected) => {
+                    stats.corrected_bits = stats
+                        .corrected_bits
+                        .saturating_add(u32::try_from(corrected).unwrap_or(u32::MAX));
+                    stats.repaired = stats.repaired.saturating_add(1);
+                }
+                Err(Error::Corrupted) => {
+                    stats.uncorrectable = stats.uncorrectable.saturating_add(1);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        for slots in triples {
+            if self.scrub_triple(slots)? == Vote::Corrected {
+                stats.repaired = stats.repaired.saturating_add(1);
+            }
+        }
+        Ok(stats)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Eeprom25aa02, Error, PageIndex, Transport, Variant, PAGE_SIZE};
+use embedded_hal::delay::DelayNs;
+
+/// Returns the walking ones patterns followed by the walking zeros
+/// patterns.
+fn patterns() -> impl Iterator<Item = u8> {
+    let ones = (0..8).map(|bit| 1u8 << bit);
+    ones.clone().chain(ones.map(|pattern| !pattern))
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Test a page of the EEPROM without losing its contents.
+    ///
+    /// The page is saved to RAM, then filled with walking ones (`0x01`,
+    /// `0x02`, ... `0x80`) and walking zeros (`0xFE`, `0xFD`, ... `0x7F`)
+    /// patterns, verifying each.
+    /// The original contents are written back and verified afterwards, even
+    /// if a pattern fails.
+    ///
+    /// This takes 17 write cycles, and is intended as a power-on self-test.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # fn read(data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::READ, 0xB0]),
+    /// #     hal::spi::Transaction::read_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # fn write(data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+    /// #   vec![
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0xB0]),
+    /// #     hal::spi::Transaction::write_vec(data),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #     hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]
+    /// # }
+    /// # let original: Vec<u8> = (0..16).collect();
+    /// # let mut expectations = read(original.clone());
+    /// # for bit in 0..8 {
+    /// #   expectations.extend(write(vec![1 << bit; 16]));
+    /// #   expectations.extend(read(vec![1 << bit; 16]));
+    /// # }
+    /// # for bit in 0..8 {
+    /// #   expectations.extend(write(vec![!(1 << bit); 16]));
+    /// #   expectations.extend(read(vec![!(1 << bit); 16]));
+    /// # }
+    /// # expectations.extend(write(original.clone()));
+    /// # expectations.extend(read(original));
+    /// # let spi = hal::spi::Mock::new(&expectations);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, PageIndex};
+    ///
+    /// const SCRATCH_PAGE: PageIndex = PageIndex::new(11).unwrap();
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// eeprom.self_test(SCRATCH_PAGE)?;
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ProtectedRegion`] if the page is in the write-protected
+    ///   upper quarter of the array.
+    /// * [`Error::VerifyFailed`] if a pattern did not verify, with the offset
+    ///   from the start of the page.
+    pub fn self_test(&mut self, page: PageIndex) -> Result<(), Error<SPI::Error>> {
+        let address: u8 = page.address().get();
+        self.check_write(address, PAGE_SIZE.into())?;
+        let original: [u8; PAGE_SIZE as usize] = self.read_array(address)?;
+
+        let result: Result<(), Error<SPI::Error>> = patterns().try_for_each(|pattern| {
+            let data: [u8; PAGE_SIZE as usize] = [pattern; PAGE_SIZE as usize];
+            self.write(address, &data)?;
+            self.verify(address, &data)
+        });
+
+        let restored: Result<(), Error<SPI::Error>> = self
+            .write(address, &original)
+            .and_then(|()| self.verify(address, &original));
+        // the pattern error is more useful
+        result.and(restored)
+    }
+}
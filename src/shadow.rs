@@ -0,0 +1,163 @@
+use crate::{
+    page_chunks, transaction::runs, Eeprom25aa02, Error, NoDelay, Transport, Variant, ARRAY_SIZE,
+    E48,
+};
+use core::ops::Range;
+use embedded_hal::delay::DelayNs;
+
+/// EEPROM driver with a RAM copy of the array.
+///
+/// Reads and writes access the RAM copy, and [`flush`](Self::flush) writes
+/// the bytes that changed to the EEPROM.
+/// This reduces SPI traffic and wear for code that updates settings often.
+///
+/// Writes are checked against the protected block and the
+/// [`WritePolicy`](crate::WritePolicy) when they are made, not when they are
+/// flushed.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x00]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00; 256]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x01]),
+/// #   hal::spi::Transaction::write_vec(vec![0x12, 0x34]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, ShadowEeprom};
+///
+/// let mut eeprom = ShadowEeprom::new(Eeprom25aa02e48::new(spi))?;
+///
+/// eeprom.write(0x01, &[0x00, 0x00])?;
+/// eeprom.write(0x01, &[0x12, 0x34])?;
+///
+/// let mut buf: [u8; 2] = [0; 2];
+/// eeprom.read(0x01, &mut buf)?;
+/// assert_eq!(buf, [0x12, 0x34]);
+///
+/// // only the changed bytes are written
+/// eeprom.flush()?;
+/// # let mut spi = eeprom.into_inner().free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct ShadowEeprom<SPI, V = E48, D = NoDelay> {
+    eeprom: Eeprom25aa02<SPI, V, D>,
+    shadow: [u8; ARRAY_SIZE],
+    /// Bit `n % 8` of byte `n / 8` is set if address `n` differs from the
+    /// EEPROM.
+    dirty: [u8; ARRAY_SIZE / 8],
+}
+
+impl<SPI, V, D> ShadowEeprom<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Creates a new shadowed driver, reading the array into RAM.
+    pub fn new(mut eeprom: Eeprom25aa02<SPI, V, D>) -> Result<Self, Error<SPI::Error>> {
+        let shadow: [u8; ARRAY_SIZE] = eeprom.read_all()?;
+        Ok(ShadowEeprom {
+            eeprom,
+            shadow,
+            dirty: [0; ARRAY_SIZE / 8],
+        })
+    }
+
+    /// Free the underlying driver.
+    ///
+    /// Changes that have not been flushed are discarded.
+    #[inline]
+    pub fn into_inner(self) -> Eeprom25aa02<SPI, V, D> {
+        self.eeprom
+    }
+
+    /// Returns `true` if any byte has changes that have not been flushed.
+    #[inline]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.iter().any(|&byte| byte != 0)
+    }
+
+    /// Read from the RAM copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the read extends past the end of
+    /// the array.
+    pub fn read(&self, address: u8, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        let start: usize = address.into();
+        let src: &[u8] = self
+            .shadow
+            .get(start..start + buf.len())
+            .ok_or(Error::OutOfBounds)?;
+        buf.copy_from_slice(src);
+        Ok(())
+    }
+
+    /// Write to the RAM copy.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OutOfBounds`] if the write extends past the end of the
+    ///   array.
+    /// * [`Error::ProtectedRegion`] if the write overlaps the
+    ///   write-protected upper quarter of the array.
+    /// * [`Error::WriteDenied`] if the write is denied by the
+    ///   [`WritePolicy`](crate::WritePolicy).
+    pub fn write(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        let start: usize = address.into();
+        if start + data.len() > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        self.eeprom.check_write(address, data.len())?;
+        for (n, &byte) in data.iter().enumerate() {
+            let address: usize = start + n;
+            if self.shadow[address] != byte {
+                self.shadow[address] = byte;
+                self.dirty[address / 8] |= 1 << (address % 8);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write every byte that changed to the EEPROM.
+    ///
+    /// Contiguous changes are written together, split at page boundaries,
+    /// so only addresses that passed the write checks are written.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    pub fn flush(&mut self) -> Result<(), Error<SPI::Error>> {
+        loop {
+            let next: Option<Range<usize>> = runs(&self.dirty).flat_map(page_chunks).next();
+            let Some(chunk) = next else {
+                break;
+            };
+            self.eeprom
+                .write(chunk.start as u8, &self.shadow[chunk.clone()])?;
+            for address in chunk {
+                self.dirty[address / 8] &= !(1 << (address % 8));
+            }
+        }
+        Ok(())
+    }
+
+    /// Discard changes that have not been flushed, reading the array into
+    /// RAM again.
+    pub fn reload(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.shadow = self.eeprom.read_all()?;
+        self.dirty = [0; ARRAY_SIZE / 8];
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    bus::WIP_POLL_LIMIT, page_chunks, Eeprom25aa02, Error, NoDelay, Transport, Variant, ARRAY_SIZE,
+    E48,
+};
+use core::cell::{Cell, RefCell};
+use critical_section::Mutex;
+use embedded_hal::delay::DelayNs;
+
+/// EEPROM driver that can be shared between multiple owners.
+///
+/// Every access runs in a critical section, so the driver can be placed in a
+/// `static` and used from tasks and interrupt handlers without threading
+/// `&mut` through the firmware.
+///
+/// Critical sections disable interrupts on most targets, keep accesses short.
+/// [`write`](Self::write) leaves the critical section between pages and
+/// between polls of the STATUS register, so other owners can run during the
+/// write cycle.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+/// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, SharedEeprom};
+///
+/// let eeprom = SharedEeprom::new(Eeprom25aa02e48::new(spi));
+///
+/// let mut buf: [u8; 2] = [0; 2];
+/// eeprom.read(0x10, &mut buf)?;
+/// assert_eq!(buf, [0x12, 0x34]);
+/// # let mut spi = eeprom.into_inner().free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct SharedEeprom<SPI, V = E48, D = NoDelay> {
+    eeprom: Mutex<RefCell<Eeprom25aa02<SPI, V, D>>>,
+    /// A write cycle started by `write` may still be in progress.
+    write_pending: Mutex<Cell<bool>>,
+}
+
+impl<SPI, V, D> SharedEeprom<SPI, V, D> {
+    /// Creates a new shared driver.
+    #[inline]
+    pub const fn new(eeprom: Eeprom25aa02<SPI, V, D>) -> Self {
+        SharedEeprom {
+            eeprom: Mutex::new(RefCell::new(eeprom)),
+            write_pending: Mutex::new(Cell::new(false)),
+        }
+    }
+
+    /// Returns the underlying EEPROM driver.
+    #[inline]
+    pub fn into_inner(self) -> Eeprom25aa02<SPI, V, D> {
+        self.eeprom.into_inner().into_inner()
+    }
+}
+
+impl<SPI, V, D> SharedEeprom<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Run `f` with exclusive access to the EEPROM driver.
+    ///
+    /// This is for sequences of operations that must not be interleaved with
+    /// other owners.
+    ///
+    /// If this interrupts a [`write`](Self::write) during a write cycle, the
+    /// write cycle is completed before `f` runs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, SharedEeprom};
+    ///
+    /// let eeprom = SharedEeprom::new(Eeprom25aa02e48::new(spi));
+    /// let busy: bool = eeprom.lock(|eeprom| eeprom.is_busy())??;
+    /// assert!(!busy);
+    /// # let mut spi = eeprom.into_inner().free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from waiting for the write cycle, such as
+    /// [`Error::Timeout`], without running `f`.
+    /// The write cycle is still pending, and the next call waits for it
+    /// again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called re-entrantly from within `f`.
+    pub fn lock<T>(
+        &self,
+        f: impl FnOnce(&mut Eeprom25aa02<SPI, V, D>) -> T,
+    ) -> Result<T, Error<SPI::Error>> {
+        critical_section::with(|cs| {
+            let mut eeprom = self.eeprom.borrow_ref_mut(cs);
+            let write_pending: &Cell<bool> = self.write_pending.borrow(cs);
+            if write_pending.get() {
+                eeprom.wait_write_complete()?;
+                write_pending.set(false);
+            }
+            Ok(f(&mut eeprom))
+        })
+    }
+
+    /// Poll the STATUS register until the pending write cycle completes,
+    /// entering a critical section for each poll.
+    fn wait_write_complete(&self) -> Result<(), Error<SPI::Error>> {
+        for _ in 0..WIP_POLL_LIMIT {
+            let complete: bool = critical_section::with(|cs| -> Result<bool, Error<SPI::Error>> {
+                let write_pending: &Cell<bool> = self.write_pending.borrow(cs);
+                if write_pending.get() && self.eeprom.borrow_ref_mut(cs).is_busy()? {
+                    Ok(false)
+                } else {
+                    write_pending.set(false);
+                    Ok(true)
+                }
+            })?;
+            if complete {
+                return Ok(());
+            }
+        }
+        Err(Error::Timeout)
+    }
+
+    /// Read from the EEPROM.
+    ///
+    /// See [`Eeprom25aa02::read`].
+    pub fn read(&self, address: u8, data: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        self.lock(|eeprom| eeprom.read(address, data))?
+    }
+
+    /// Write to the EEPROM.
+    ///
+    /// Each page is written in its own critical section, and the critical
+    /// section is left between polls of the STATUS register.
+    ///
+    /// See [`Eeprom25aa02::write`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let mut transactions = Vec::new();
+    /// # for (address, data, status) in [(0x0E, [0x01, 0x02], vec![0x01, 0x00]), (0x10, [0x03, 0x04], vec![0x00])] {
+    /// #   transactions.extend([
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #     hal::spi::Transaction::transaction_start(),
+    /// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+    /// #     hal::spi::Transaction::write_vec(data.to_vec()),
+    /// #     hal::spi::Transaction::transaction_end(),
+    /// #   ]);
+    /// #   for status in status {
+    /// #     transactions.extend([
+    /// #       hal::spi::Transaction::transaction_start(),
+    /// #       hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #       hal::spi::Transaction::read_vec(vec![status]),
+    /// #       hal::spi::Transaction::transaction_end(),
+    /// #     ]);
+    /// #   }
+    /// # }
+    /// # let spi = hal::spi::Mock::new(&transactions);
+    /// use eeprom25aa02e48::{Eeprom25aa02e48, SharedEeprom};
+    ///
+    /// let eeprom = SharedEeprom::new(Eeprom25aa02e48::new(spi));
+    /// eeprom.write(0x0E, &[0x01, 0x02, 0x03, 0x04])?;
+    /// # let mut spi = eeprom.into_inner().free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn write(&self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if usize::from(address) + data.len() > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        self.lock(|eeprom| eeprom.check_write(address, data.len()))??;
+
+        let start: usize = address.into();
+        for chunk in page_chunks(start..start + data.len()) {
+            let data: &[u8] = &data[chunk.start - start..chunk.end - start];
+            critical_section::with(|cs| -> Result<(), Error<SPI::Error>> {
+                let started: bool = self
+                    .eeprom
+                    .borrow_ref_mut(cs)
+                    .start_chunk_write(chunk.start as u8, data)?;
+                self.write_pending.borrow(cs).set(started);
+                Ok(())
+            })?;
+            self.wait_write_complete()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{
+    crc::{crc8, crc8_update},
+    page_chunks, Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE, PAGE_SIZE,
+};
+use embedded_hal::delay::DelayNs;
+
+/// Two copies of a record for power-loss safe updates.
+///
+/// Each slot is stored as a sequence number, the data, and a CRC-8 over
+/// both.
+/// Writes always go to the inactive slot, and reads return the valid slot
+/// with the newest sequence number.
+/// If power is lost during a write the previous copy remains valid.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x40]),
+/// #   hal::spi::Transaction::read_vec(vec![0x01, 0x01, 0x02, 0x03, 0x04, 0xBB]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x48]),
+/// #   hal::spi::Transaction::read_vec(vec![0xFF; 6]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x48]),
+/// #   hal::spi::Transaction::write_vec(vec![0x02, 0x05, 0x06, 0x07, 0x08, 0xFC]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x40]),
+/// #   hal::spi::Transaction::read_vec(vec![0x01, 0x01, 0x02, 0x03, 0x04, 0xBB]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x48]),
+/// #   hal::spi::Transaction::read_vec(vec![0x02, 0x05, 0x06, 0x07, 0x08, 0xFC]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x49]),
+/// #   hal::spi::Transaction::read_vec(vec![0x05, 0x06, 0x07, 0x08]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{AbSlots, Eeprom25aa02e48};
+///
+/// const CONFIG: AbSlots = AbSlots::new(0x40, 0x48, 4);
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// eeprom.write_slots(&CONFIG, &[5, 6, 7, 8])?;
+///
+/// let mut config: [u8; 4] = [0; 4];
+/// eeprom.read_slots(&CONFIG, &mut config)?;
+/// assert_eq!(config, [5, 6, 7, 8]);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AbSlots {
+    a: u8,
+    b: u8,
+    size: usize,
+}
+
+impl AbSlots {
+    /// Size of the sequence number and CRC in bytes.
+    pub const OVERHEAD: usize = 2;
+
+    /// Creates a new pair of slots holding `size` bytes of data each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slots overlap or extend past the end of the array.
+    #[inline]
+    pub const fn new(a: u8, b: u8, size: usize) -> Self {
+        let len: usize = size + Self::OVERHEAD;
+        assert!(
+            a as usize + len <= ARRAY_SIZE && b as usize + len <= ARRAY_SIZE,
+            "slot extends past the end of the array"
+        );
+        assert!(
+            a as usize + len <= b as usize || b as usize + len <= a as usize,
+            "slots overlap"
+        );
+        AbSlots { a, b, size }
+    }
+
+    /// EEPROM memory addresses of the A and B slots.
+    #[inline]
+    pub const fn addresses(&self) -> (u8, u8) {
+        (self.a, self.b)
+    }
+
+    /// Size of the data in each slot in bytes.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// Returns `true` if sequence number `a` is newer than `b`.
+pub(crate) fn is_newer(a: u8, b: u8) -> bool {
+    (a.wrapping_sub(b) as i8) > 0
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Read the newest valid copy of an A/B slot pair.
+    ///
+    /// If `buf` is shorter than the slot only the start of the data is read.
+    ///
+    /// See [`AbSlots`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::DataTooLong`] if `buf` is longer than the slot.
+    /// * [`Error::Corrupted`] if neither slot is valid.
+    ///
+    /// Slots that read as all `0x00`, for example from a missing device with
+    /// MISO pulled low, are not valid.
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x40]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00; 6]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x48]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00; 6]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{AbSlots, Eeprom25aa02e48, Error};
+    ///
+    /// const CONFIG: AbSlots = AbSlots::new(0x40, 0x48, 4);
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let mut config: [u8; 4] = [0; 4];
+    /// assert_eq!(eeprom.read_slots(&CONFIG, &mut config), Err(Error::Corrupted));
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// ```
+    pub fn read_slots(&mut self, slots: &AbSlots, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        if buf.len() > slots.size {
+            return Err(Error::DataTooLong);
+        }
+        let (address, _) = self
+            .newest_slot([slots.a, slots.b], slots.size + AbSlots::OVERHEAD)?
+            .ok_or(Error::Corrupted)?;
+        self.read(address + 1, buf)
+    }
+
+    /// Write to the inactive slot of an A/B slot pair.
+    ///
+    /// If `data` is shorter than the slot the remainder is filled with
+    /// `0xFF`.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// See [`AbSlots`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if `data` is longer than the slot.
+    pub fn write_slots(&mut self, slots: &AbSlots, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        if data.len() > slots.size {
+            return Err(Error::DataTooLong);
+        }
+        let slot_size: usize = slots.size + AbSlots::OVERHEAD;
+        let (address, sequence): (u8, u8) = match self.newest_slot([slots.a, slots.b], slot_size)? {
+            Some((active, sequence)) if active == slots.a => (slots.b, sequence.wrapping_add(1)),
+            Some((_, sequence)) => (slots.a, sequence.wrapping_add(1)),
+            None => (slots.a, 0),
+        };
+        self.write_slot(address, slot_size, sequence, &[data])
+    }
+}
+
+/// Contents of a slot without the CRC: the sequence number, each part in
+/// turn, then `0xFF` padding.
+fn slot_bytes<'p>(
+    sequence: u8,
+    parts: &'p [&'p [u8]],
+    slot_size: usize,
+) -> impl Iterator<Item = u8> + 'p {
+    core::iter::once(sequence)
+        .chain(parts.iter().flat_map(|part| part.iter().copied()))
+        .chain(core::iter::repeat(0xFF))
+        .take(slot_size - 1)
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Check the CRC of a slot of `slot_size` bytes, reading it a page at a
+    /// time.
+    ///
+    /// Returns the sequence number if the CRC is valid.
+    pub(crate) fn check_slot(
+        &mut self,
+        address: u8,
+        slot_size: usize,
+    ) -> Result<Option<u8>, Error<SPI::Error>> {
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        let mut crc: u8 = crc8(&[]);
+        let mut sequence: u8 = 0;
+        let mut last: u8 = 0;
+        for offset in (0..slot_size).step_by(PAGE_SIZE.into()) {
+            let chunk: &mut [u8] = &mut buf[..(slot_size - offset).min(PAGE_SIZE.into())];
+            // the caller checks that the slot is within the array
+            self.read(address + offset as u8, chunk)?;
+            if offset == 0 {
+                sequence = chunk[0];
+            }
+            let (data, tail) = chunk.split_at(chunk.len() - 1);
+            crc = crc8_update(crc, data);
+            if offset + chunk.len() < slot_size {
+                crc = crc8_update(crc, tail);
+            }
+            last = tail[0];
+        }
+        Ok((crc == last).then_some(sequence))
+    }
+
+    /// Returns the address and sequence number of the newest valid slot.
+    pub(crate) fn newest_slot(
+        &mut self,
+        addresses: impl IntoIterator<Item = u8>,
+        slot_size: usize,
+    ) -> Result<Option<(u8, u8)>, Error<SPI::Error>> {
+        let mut newest: Option<(u8, u8)> = None;
+        for address in addresses {
+            if let Some(sequence) = self.check_slot(address, slot_size)? {
+                if newest.is_none_or(|(_, newest)| is_newer(sequence, newest)) {
+                    newest = Some((address, sequence));
+                }
+            }
+        }
+        Ok(newest)
+    }
+
+    /// Write a slot of `slot_size` bytes: the sequence number, each part in
+    /// turn, `0xFF` padding, and a CRC-8 over all of them.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    pub(crate) fn write_slot(
+        &mut self,
+        address: u8,
+        slot_size: usize,
+        sequence: u8,
+        parts: &[&[u8]],
+    ) -> Result<(), Error<SPI::Error>> {
+        let crc: u8 =
+            slot_bytes(sequence, parts, slot_size).fold(crc8(&[]), |crc, b| crc8_update(crc, &[b]));
+        let mut bytes = slot_bytes(sequence, parts, slot_size).chain(core::iter::once(crc));
+        let start: usize = address.into();
+        let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+        for chunk in page_chunks(start..start + slot_size) {
+            let page: &mut [u8] = &mut buf[..chunk.len()];
+            page.iter_mut()
+                .zip(&mut bytes)
+                .for_each(|(dst, src)| *dst = src);
+            self.write(chunk.start as u8, page)?;
+        }
+        Ok(())
+    }
+}
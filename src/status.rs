@@ -0,0 +1,117 @@
+/// Array block write protection.
+///
+/// Set by the BP1 and BP0 bits of the STATUS register.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum BlockProtect {
+    /// No write protection.
+    #[default]
+    None = 0b00,
+    /// Upper quarter, 0xC0 to 0xFF, is write protected.
+    UpperQuarter = 0b01,
+    /// Upper half, 0x80 to 0xFF, is write protected.
+    UpperHalf = 0b10,
+    /// All sectors, 0x00 to 0xFF, are write protected.
+    All = 0b11,
+}
+
+impl BlockProtect {
+    /// Lowest write protected address, `None` if nothing is protected.
+    #[inline]
+    pub const fn start_address(&self) -> Option<u8> {
+        match self {
+            BlockProtect::None => None,
+            BlockProtect::UpperQuarter => Some(0xC0),
+            BlockProtect::UpperHalf => Some(0x80),
+            BlockProtect::All => Some(0x00),
+        }
+    }
+
+    /// STATUS register bits for this block protection.
+    #[inline]
+    pub(crate) const fn bits(self) -> u8 {
+        (self as u8) << 2
+    }
+}
+
+/// STATUS register.
+///
+/// Returned by [`Eeprom25aa02e48::read_status`](crate::Eeprom25aa02e48::read_status).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusRegister(u8);
+
+impl StatusRegister {
+    /// Write-in-progress bit mask.
+    pub const WIP: u8 = 1 << 0;
+    /// Write enable latch bit mask.
+    pub const WEL: u8 = 1 << 1;
+    /// Block protection bit 0 mask.
+    pub const BP0: u8 = 1 << 2;
+    /// Block protection bit 1 mask.
+    pub const BP1: u8 = 1 << 3;
+
+    /// Create a STATUS register value from raw bits.
+    #[inline]
+    pub const fn from_bits(bits: u8) -> Self {
+        StatusRegister(bits)
+    }
+
+    /// Raw bits of the STATUS register.
+    #[inline]
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if an internal write cycle is in progress.
+    #[inline]
+    pub const fn wip(&self) -> bool {
+        self.0 & Self::WIP != 0
+    }
+
+    /// Returns `true` if the write enable latch is set.
+    #[inline]
+    pub const fn wel(&self) -> bool {
+        self.0 & Self::WEL != 0
+    }
+
+    /// Block protection bit 0.
+    #[inline]
+    pub const fn bp0(&self) -> bool {
+        self.0 & Self::BP0 != 0
+    }
+
+    /// Block protection bit 1.
+    #[inline]
+    pub const fn bp1(&self) -> bool {
+        self.0 & Self::BP1 != 0
+    }
+
+    /// Block protection set by the BP1 and BP0 bits.
+    #[inline]
+    pub const fn block_protection(&self) -> BlockProtect {
+        match (self.bp1(), self.bp0()) {
+            (false, false) => BlockProtect::None,
+            (false, true) => BlockProtect::UpperQuarter,
+            (true, false) => BlockProtect::UpperHalf,
+            (true, true) => BlockProtect::All,
+        }
+    }
+}
+
+impl From<u8> for StatusRegister {
+    #[inline]
+    fn from(bits: u8) -> Self {
+        StatusRegister(bits)
+    }
+}
+
+impl From<StatusRegister> for u8 {
+    #[inline]
+    fn from(status: StatusRegister) -> Self {
+        status.0
+    }
+}
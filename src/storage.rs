@@ -0,0 +1,80 @@
+use crate::{Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE};
+use embedded_hal::delay::DelayNs;
+use embedded_storage::{ReadStorage, Storage};
+
+/// Check that an access lies entirely within the array.
+fn check_bounds<E>(offset: u32, len: usize) -> Result<u8, Error<E>> {
+    let address: u8 = u8::try_from(offset).map_err(|_| Error::OutOfBounds)?;
+    if usize::from(address) + len > ARRAY_SIZE {
+        Err(Error::OutOfBounds)
+    } else {
+        Ok(address)
+    }
+}
+
+/// Read the array through the [`embedded_storage`] traits.
+///
+/// Accesses extending past the end of the array return
+/// [`Error::OutOfBounds`].
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+/// #   hal::spi::Transaction::read_vec(vec![0x12, 0x34]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+/// use embedded_storage::ReadStorage;
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// assert_eq!(eeprom.capacity(), 256);
+///
+/// let mut buf: [u8; 2] = [0; 2];
+/// ReadStorage::read(&mut eeprom, 0x10, &mut buf)?;
+/// assert_eq!(buf, [0x12, 0x34]);
+///
+/// assert_eq!(
+///     ReadStorage::read(&mut eeprom, 0xFF, &mut buf),
+///     Err(Error::OutOfBounds)
+/// );
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+impl<SPI, V, D> ReadStorage for Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    type Error = Error<SPI::Error>;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let address: u8 = check_bounds(offset, bytes.len())?;
+        Eeprom25aa02::read(self, address, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        ARRAY_SIZE
+    }
+}
+
+/// Write the array through the [`embedded_storage`] traits.
+///
+/// Writes are split into pages, and the driver waits for each write cycle,
+/// see [`Eeprom25aa02::write`] and [write cycles](crate#write-cycles).
+impl<SPI, V, D> Storage for Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let address: u8 = check_bounds(offset, bytes.len())?;
+        Eeprom25aa02::write(self, address, bytes)
+    }
+}
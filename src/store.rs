@@ -0,0 +1,178 @@
+use crate::{Codec, CodecError, Eeprom25aa02, Error, Transport, Variant, ARRAY_SIZE};
+use embedded_hal::delay::DelayNs;
+use serde::{de::DeserializeOwned, Serialize};
+
+#[cfg(feature = "postcard")]
+use crate::Postcard;
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Store a value at an EEPROM memory address, serialized with a
+    /// [`Codec`].
+    ///
+    /// The value is stored with a single byte length prefix, and may span
+    /// multiple pages.
+    /// `buf` holds the serialized value, and must be at least as long as it.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::DataTooLong`] if the serialized value does not fit in
+    ///   `buf`, or is longer than 255 bytes.
+    /// * [`Error::OutOfBounds`] if the serialized value extends past the end
+    ///   of the array.
+    /// * [`Error::Serialization`] if the value cannot be serialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "cbor")] {
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x40]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x04, 0x81, 0x19, 0x03, 0xE8]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x40]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x04]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x41]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x81, 0x19, 0x03, 0xE8]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::{Cbor, Eeprom25aa02e48};
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let mut buf: [u8; 8] = [0; 8];
+    /// eeprom.store_with::<Cbor, _>(0x40, &[1000u16], &mut buf)?;
+    /// assert_eq!(eeprom.load_with::<Cbor, [u16; 1]>(0x40, &mut buf)?, [1000]);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # }
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    pub fn store_with<C: Codec, T: Serialize + ?Sized>(
+        &mut self,
+        address: u8,
+        value: &T,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        let len: usize = C::encode(value, buf).map_err(CodecError::into_error)?;
+        let prefix: u8 = u8::try_from(len).map_err(|_| Error::DataTooLong)?;
+        self.write_parts(address, &[&[prefix], &buf[..len]])
+    }
+
+    /// Load a value stored with [`Eeprom25aa02::store_with`].
+    ///
+    /// `buf` holds the serialized value while it is decoded.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Corrupted`] if the length prefix extends past the end of
+    ///   the array, or the value cannot be deserialized.
+    /// * [`Error::DataTooLong`] if `buf` is shorter than the serialized
+    ///   value.
+    pub fn load_with<C: Codec, T: DeserializeOwned>(
+        &mut self,
+        address: u8,
+        buf: &mut [u8],
+    ) -> Result<T, Error<SPI::Error>> {
+        let mut len: [u8; 1] = [0];
+        self.read(address, &mut len)?;
+        let len: usize = len[0].into();
+        if usize::from(address) + 1 + len > ARRAY_SIZE {
+            return Err(Error::Corrupted);
+        }
+        let buf: &mut [u8] = buf.get_mut(..len).ok_or(Error::DataTooLong)?;
+        self.read(address + 1, buf)?;
+        C::decode(buf).map_err(CodecError::into_error)
+    }
+
+    /// Store a value with [postcard] at an EEPROM memory address.
+    ///
+    /// See [`Eeprom25aa02::store_with`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eeprom25aa02e48::instruction;
+    /// # use embedded_hal_mock::eh1 as hal;
+    /// # let spi = hal::spi::Mock::new(&[
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x20]),
+    /// #   hal::spi::Transaction::write_vec(vec![0x07, 0xE8, 0x07, 0x01, 0x03, b'l', b'a', b'b']),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x00]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x20]),
+    /// #   hal::spi::Transaction::read_vec(vec![0x07]),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// #   hal::spi::Transaction::transaction_start(),
+    /// #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x21]),
+    /// #   hal::spi::Transaction::read_vec(vec![0xE8, 0x07, 0x01, 0x03, b'l', b'a', b'b']),
+    /// #   hal::spi::Transaction::transaction_end(),
+    /// # ]);
+    /// use eeprom25aa02e48::Eeprom25aa02e48;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Settings {
+    ///     timeout_ms: u16,
+    ///     enabled: bool,
+    ///     name: String,
+    /// }
+    ///
+    /// let settings = Settings {
+    ///     timeout_ms: 1000,
+    ///     enabled: true,
+    ///     name: "lab".into(),
+    /// };
+    ///
+    /// let mut eeprom = Eeprom25aa02e48::new(spi);
+    /// let mut buf: [u8; 16] = [0; 16];
+    /// eeprom.store(0x20, &settings, &mut buf)?;
+    /// assert_eq!(eeprom.load::<Settings>(0x20, &mut buf)?, settings);
+    /// # let mut spi = eeprom.free(); spi.done();
+    /// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+    /// ```
+    #[cfg(feature = "postcard")]
+    pub fn store<T: Serialize + ?Sized>(
+        &mut self,
+        address: u8,
+        value: &T,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        self.store_with::<Postcard, T>(address, value, buf)
+    }
+
+    /// Load a value stored with [`Eeprom25aa02::store`].
+    ///
+    /// See [`Eeprom25aa02::load_with`].
+    #[cfg(feature = "postcard")]
+    pub fn load<T: DeserializeOwned>(
+        &mut self,
+        address: u8,
+        buf: &mut [u8],
+    ) -> Result<T, Error<SPI::Error>> {
+        self.load_with::<Postcard, T>(address, buf)
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{Eeprom25aa02, Error, Transport, Variant, Vote, ARRAY_SIZE, PAGE_SIZE};
+use embedded_hal::delay::DelayNs;
+
+/// Three copies of critical data, read with a majority vote.
+///
+/// Writes go to every copy, and reads correct each bit with a 2-of-3
+/// majority vote, rewriting any copy that disagrees with the result.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # fn read(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+/// #     hal::spi::Transaction::read_vec(data),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # fn write(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+/// #     hal::spi::Transaction::write_vec(data),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #     hal::spi::Transaction::read_vec(vec![0x00]),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # let mut expectations = vec![];
+/// # expectations.extend(write(0x50, vec![0xCA, 0x1B]));
+/// # expectations.extend(write(0x58, vec![0xCA, 0x1B]));
+/// # expectations.extend(write(0x60, vec![0xCA, 0x1B]));
+/// # expectations.extend(read(0x50, vec![0xCA, 0x1B]));
+/// # expectations.extend(read(0x58, vec![0xCA, 0x1F]));
+/// # expectations.extend(read(0x60, vec![0xCA, 0x1B]));
+/// # expectations.extend(write(0x58, vec![0xCA, 0x1B]));
+/// # let spi = hal::spi::Mock::new(&expectations);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, TripleSlots, Vote};
+///
+/// const CALIBRATION_VALID: TripleSlots = TripleSlots::new([0x50, 0x58, 0x60], 2);
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// eeprom.write_triple(&CALIBRATION_VALID, &[0xCA, 0x1B])?;
+///
+/// let mut buf: [u8; 2] = [0; 2];
+/// assert_eq!(eeprom.read_triple(&CALIBRATION_VALID, &mut buf)?, Vote::Corrected);
+/// assert_eq!(buf, [0xCA, 0x1B]);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TripleSlots {
+    addresses: [u8; 3],
+    size: usize,
+}
+
+impl TripleSlots {
+    /// Creates a new set of three slots holding `size` bytes each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slots are empty, overlap, or extend past the end of the
+    /// array.
+    ///
+    /// In a constant this is a compile time error.
+    ///
+    /// ```compile_fail
+    /// use eeprom25aa02e48::TripleSlots;
+    ///
+    /// const EMPTY: TripleSlots = TripleSlots::new([0x50, 0x58, 0x60], 0);
+    /// ```
+    #[inline]
+    pub const fn new(addresses: [u8; 3], size: usize) -> Self {
+        assert!(size > 0, "slots are empty");
+        let mut i: usize = 0;
+        while i < 3 {
+            assert!(
+                addresses[i] as usize + size <= ARRAY_SIZE,
+                "slot extends past the end of the array"
+            );
+            let mut j: usize = i + 1;
+            while j < 3 {
+                let (a, b) = (addresses[i] as usize, addresses[j] as usize);
+                assert!(a + size <= b || b + size <= a, "slots overlap");
+                j += 1;
+            }
+            i += 1;
+        }
+        TripleSlots { addresses, size }
+    }
+
+    /// EEPROM memory addresses of the slots.
+    #[inline]
+    pub const fn addresses(&self) -> [u8; 3] {
+        self.addresses
+    }
+
+    /// Size of the data in each slot in bytes.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Write data to every slot.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    ///
+    /// See [`TripleSlots`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if the data is not the size of a slot.
+    pub fn write_triple(
+        &mut self,
+        slots: &TripleSlots,
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        if data.len() != slots.size {
+            return Err(Error::DataTooLong);
+        }
+        for address in slots.addresses {
+            self.write(address, data)?;
+        }
+        Ok(())
+    }
+
+    /// Read the slots into `buf` with a bitwise majority vote.
+    ///
+    /// Slots that disagree with the result are rewritten, and
+    /// [`Vote::Corrected`] is returned.
+    ///
+    /// See [`TripleSlots`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if `buf` is not the size of a slot.
+    pub fn read_triple(
+        &mut self,
+        slots: &TripleSlots,
+        buf: &mut [u8],
+    ) -> Result<Vote, Error<SPI::Error>> {
+        if buf.len() != slots.size {
+            return Err(Error::DataTooLong);
+        }
+        let mut vote: Vote = Vote::Unanimous;
+        for (offset, chunk) in (0..)
+            .step_by(PAGE_SIZE.into())
+            .zip(buf.chunks_mut(PAGE_SIZE.into()))
+        {
+            if self.vote_triple_chunk(slots, offset, chunk)? == Vote::Corrected {
+                vote = Vote::Corrected;
+            }
+        }
+        Ok(vote)
+    }
+
+    /// Rewrite any slot that disagrees with the majority vote.
+    ///
+    /// This is [`Eeprom25aa02::read_triple`] without the data.
+    ///
+    /// See [`Eeprom25aa02::scrub`] for an example.
+    pub fn scrub_triple(&mut self, slots: &TripleSlots) -> Result<Vote, Error<SPI::Error>> {
+        let mut vote: Vote = Vote::Unanimous;
+        for offset in (0..slots.size).step_by(PAGE_SIZE.into()) {
+            let mut buf: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+            let chunk: &mut [u8] = &mut buf[..(slots.size - offset).min(PAGE_SIZE.into())];
+            if self.vote_triple_chunk(slots, offset, chunk)? == Vote::Corrected {
+                vote = Vote::Corrected;
+            }
+        }
+        Ok(vote)
+    }
+
+    /// Vote on up to a page of the slots at `offset` into `buf`, rewriting
+    /// the copies that disagree.
+    fn vote_triple_chunk(
+        &mut self,
+        slots: &TripleSlots,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<Vote, Error<SPI::Error>> {
+        let mut copies: [[u8; PAGE_SIZE as usize]; 3] = [[0; PAGE_SIZE as usize]; 3];
+        for (address, copy) in slots.addresses.into_iter().zip(copies.iter_mut()) {
+            // within the slot, checked by TripleSlots::new
+            self.read(address + offset as u8, &mut copy[..buf.len()])?;
+        }
+
+        let [a, b, c] = &copies;
+        buf.iter_mut()
+            .zip(a.iter().zip(b.iter().zip(c.iter())))
+            .for_each(|(byte, (a, (b, c)))| *byte = (a & b) | (a & c) | (b & c));
+
+        let mut vote: Vote = Vote::Unanimous;
+        for (address, copy) in slots.addresses.into_iter().zip(copies.iter()) {
+            if copy[..buf.len()] != *buf {
+                self.write(address + offset as u8, buf)?;
+                vote = Vote::Corrected;
+            }
+        }
+        Ok(vote)
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    page_chunks, Eeprom25aa02, Error, NoDelay, Transport, Variant, ARRAY_SIZE, E48, PAGE_SIZE,
+};
+use core::ops::Range;
+use embedded_hal::delay::DelayNs;
+
+/// Staged writes that are committed all-or-nothing.
+///
+/// Created by [`Eeprom25aa02::begin_transaction`].
+///
+/// Writes are checked against the [`WritePolicy`](crate::WritePolicy) and
+/// staged in RAM.
+/// On [`commit`](Self::commit) every staged write is written and verified,
+/// if any write fails the original data is restored.
+/// Dropping the transaction, or calling [`rollback`](Self::rollback),
+/// discards the staged writes without touching the EEPROM.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # fn read(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+/// #     hal::spi::Transaction::read_vec(data),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # fn write(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::WRITE, address]),
+/// #     hal::spi::Transaction::write_vec(data),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #     hal::spi::Transaction::read_vec(vec![0x00]),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # let mut expectations = vec![];
+/// # expectations.extend(read(0x10, vec![0x00, 0x00]));
+/// # expectations.extend(write(0x10, vec![0x12, 0x34]));
+/// # expectations.extend(read(0x10, vec![0x12, 0x34]));
+/// # expectations.extend(read(0x20, vec![0x00]));
+/// # expectations.extend(write(0x20, vec![0x56]));
+/// # expectations.extend(read(0x20, vec![0x56]));
+/// # let spi = hal::spi::Mock::new(&expectations);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, Error};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+///
+/// let mut transaction = eeprom.begin_transaction();
+/// transaction.write(0x10, &[0x12, 0x34])?;
+/// transaction.write(0x20, &[0x56])?;
+/// assert_eq!(transaction.write(0xFA, &[0x00]), Err(Error::ProtectedRegion));
+/// transaction.commit()?;
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct Transaction<'a, SPI, V = E48, D = NoDelay> {
+    eeprom: &'a mut Eeprom25aa02<SPI, V, D>,
+    staged: [u8; ARRAY_SIZE],
+    dirty: [u8; ARRAY_SIZE / 8],
+}
+
+pub(crate) fn is_dirty(dirty: &[u8; ARRAY_SIZE / 8], address: usize) -> bool {
+    dirty[address / 8] & (1 << (address % 8)) != 0
+}
+
+/// Returns an iterator over the staged ranges of contiguous addresses.
+pub(crate) fn runs(dirty: &[u8; ARRAY_SIZE / 8]) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut address: usize = 0;
+    core::iter::from_fn(move || {
+        while address < ARRAY_SIZE && !is_dirty(dirty, address) {
+            address += 1;
+        }
+        let start: usize = address;
+        while address < ARRAY_SIZE && is_dirty(dirty, address) {
+            address += 1;
+        }
+        (start < address).then_some(start..address)
+    })
+}
+
+impl<SPI, V, D> Transaction<'_, SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Returns `true` if no writes are staged.
+    pub fn is_empty(&self) -> bool {
+        self.dirty.iter().all(|&byte| byte == 0)
+    }
+
+    /// Stage a write.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OutOfBounds`] if the write extends past the end of the
+    ///   array.
+    /// * [`Error::ProtectedRegion`] if the write overlaps the
+    ///   write-protected upper quarter of the array.
+    /// * [`Error::WriteDenied`] if the write is denied by the
+    ///   [`WritePolicy`](crate::WritePolicy).
+    pub fn write(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        let start: usize = address.into();
+        if start + data.len() > ARRAY_SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        self.eeprom.check_write(address, data.len())?;
+        self.staged[start..start + data.len()].copy_from_slice(data);
+        for address in start..start + data.len() {
+            self.dirty[address / 8] |= 1 << (address % 8);
+        }
+        Ok(())
+    }
+
+    /// Read from the EEPROM, including staged writes.
+    pub fn read(&mut self, address: u8, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        self.eeprom.read(address, buf)?;
+        let start: usize = address.into();
+        for (n, byte) in buf.iter_mut().enumerate() {
+            if is_dirty(&self.dirty, start + n) {
+                *byte = self.staged[start + n];
+            }
+        }
+        Ok(())
+    }
+
+    /// Write and verify every staged write.
+    ///
+    /// If a write or verification fails the original data is written back,
+    /// and the error is returned.
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    pub fn commit(self) -> Result<(), Error<SPI::Error>> {
+        let Transaction {
+            eeprom,
+            mut staged,
+            dirty,
+        } = self;
+
+        // staged data is swapped with the original data as each page is
+        // written, so the EEPROM can be restored without a second copy
+        let mut written: usize = 0;
+        let mut result: Result<(), Error<SPI::Error>> = Ok(());
+        for chunk in runs(&dirty).flat_map(page_chunks) {
+            let mut original: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+            let original: &mut [u8] = &mut original[..chunk.len()];
+            result = eeprom.read(chunk.start as u8, original);
+            if result.is_err() {
+                break;
+            }
+            let data: &mut [u8] = &mut staged[chunk.clone()];
+            result = eeprom
+                .write(chunk.start as u8, data)
+                .and_then(|()| eeprom.verify(chunk.start as u8, data));
+            data.swap_with_slice(original);
+            written += 1;
+            if result.is_err() {
+                break;
+            }
+        }
+
+        if result.is_err() {
+            for chunk in runs(&dirty).flat_map(page_chunks).take(written) {
+                // best effort, the original error is more useful
+                eeprom.write(chunk.start as u8, &staged[chunk]).ok();
+            }
+        }
+        result
+    }
+
+    /// Discard every staged write.
+    #[inline]
+    pub fn rollback(self) {}
+}
+
+impl<SPI, V, D> Eeprom25aa02<SPI, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Start a transaction.
+    ///
+    /// See [`Transaction`] for an example.
+    #[inline]
+    pub fn begin_transaction(&mut self) -> Transaction<'_, SPI, V, D> {
+        Transaction {
+            eeprom: self,
+            staged: [0; ARRAY_SIZE],
+            dirty: [0; ARRAY_SIZE / 8],
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use embedded_hal::spi::{Operation, SpiDevice};
+
+/// Transport used by the driver to communicate with the EEPROM.
+///
+/// This is implemented for every [`SpiDevice`], implement it directly for
+/// backends that are not based on `embedded-hal`, such as remote bridges,
+/// test harnesses, or operating system interfaces.
+///
+/// Each call to [`transaction`](Self::transaction) must behave like
+/// [`SpiDevice::transaction`]: chip select is asserted for the duration of
+/// the operations, and deasserted afterwards.
+///
+/// # Example
+///
+/// ```
+/// use eeprom25aa02e48::{Eeprom25aa02e48, Transport};
+/// use embedded_hal::spi::{ErrorKind, Operation};
+///
+/// /// Transport that reads back all ones, like a bus with no device.
+/// struct Floating;
+///
+/// impl Transport for Floating {
+///     type Error = ErrorKind;
+///
+///     fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), ErrorKind> {
+///         for op in operations {
+///             if let Operation::Read(buf) = op {
+///                 buf.fill(0xFF);
+///             }
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut eeprom = Eeprom25aa02e48::new(Floating);
+/// assert_eq!(eeprom.read_byte(0x00)?, 0xFF);
+/// # Ok::<(), eeprom25aa02e48::Error<ErrorKind>>(())
+/// ```
+pub trait Transport {
+    /// Transport error type.
+    type Error: embedded_hal::spi::Error;
+
+    /// Perform a transaction with chip select asserted.
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error>;
+}
+
+impl<T: SpiDevice> Transport for T {
+    type Error = T::Error;
+
+    #[inline]
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+        SpiDevice::transaction(self, operations)
+    }
+}
@@ -0,0 +1,185 @@
+//! Typestate driver with explicit write unlocking.
+//!
+//! Write methods only exist on an [`Eeprom`] in the [`Unlocked`] state, and
+//! unlocking is an explicit transition.
+//! This prevents accidental writes in read-mostly applications.
+//!
+//! ```compile_fail
+//! # use embedded_hal_mock::eh1 as hal;
+//! # let spi = hal::spi::Mock::new(&[]);
+//! use eeprom25aa02e48::{typestate::Eeprom, Eeprom25aa02e48};
+//!
+//! let mut eeprom = Eeprom::new(Eeprom25aa02e48::new(spi));
+//! eeprom.write(0x10, &[0x12, 0x34])?;
+//! # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! # use eeprom25aa02e48::instruction;
+//! # use embedded_hal_mock::eh1 as hal;
+//! # let spi = hal::spi::Mock::new(&[
+//! #   hal::spi::Transaction::transaction_start(),
+//! #   hal::spi::Transaction::write_vec(vec![instruction::READ, 0x10]),
+//! #   hal::spi::Transaction::read_vec(vec![0x00]),
+//! #   hal::spi::Transaction::transaction_end(),
+//! #   hal::spi::Transaction::transaction_start(),
+//! #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+//! #   hal::spi::Transaction::transaction_end(),
+//! #   hal::spi::Transaction::transaction_start(),
+//! #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+//! #   hal::spi::Transaction::write_vec(vec![0x01]),
+//! #   hal::spi::Transaction::transaction_end(),
+//! # ]);
+//! use eeprom25aa02e48::{
+//!     typestate::{Eeprom, Locked, Unlocked},
+//!     Eeprom25aa02e48,
+//! };
+//!
+//! let mut eeprom: Eeprom<_, Locked> = Eeprom::new(Eeprom25aa02e48::new(spi));
+//! let boot_count: u8 = eeprom.read_byte(0x10)?;
+//!
+//! let mut eeprom: Eeprom<_, Unlocked> = eeprom.unlock();
+//! eeprom.write_byte(0x10, boot_count + 1)?;
+//! let eeprom: Eeprom<_, Locked> = eeprom.lock();
+//! # let mut spi = eeprom.free().free(); spi.done();
+//! # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+//! ```
+
+use crate::{Eeprom25aa02, Error, NoDelay, StatusRegister, Transport, Variant, E48};
+use core::marker::PhantomData;
+use embedded_hal::delay::DelayNs;
+
+/// Locked state, writes are not available.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Locked;
+
+/// Unlocked state, writes are available.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Unlocked;
+
+/// Typestate EEPROM driver.
+///
+/// Created in the [`Locked`] state by [`Eeprom::new`].
+pub struct Eeprom<SPI, S = Locked, V = E48, D = NoDelay> {
+    eeprom: Eeprom25aa02<SPI, V, D>,
+    state: PhantomData<S>,
+}
+
+impl<SPI, V, D> Eeprom<SPI, Locked, V, D> {
+    /// Creates a new locked driver.
+    #[inline]
+    pub fn new(eeprom: Eeprom25aa02<SPI, V, D>) -> Self {
+        Eeprom {
+            eeprom,
+            state: PhantomData,
+        }
+    }
+
+    /// Unlock the driver, allowing writes.
+    #[inline]
+    pub fn unlock(self) -> Eeprom<SPI, Unlocked, V, D> {
+        Eeprom {
+            eeprom: self.eeprom,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<SPI, V, D> Eeprom<SPI, Unlocked, V, D> {
+    /// Lock the driver, preventing writes.
+    #[inline]
+    pub fn lock(self) -> Eeprom<SPI, Locked, V, D> {
+        Eeprom {
+            eeprom: self.eeprom,
+            state: PhantomData,
+        }
+    }
+
+    /// Returns the underlying EEPROM driver.
+    #[inline]
+    pub fn eeprom(&mut self) -> &mut Eeprom25aa02<SPI, V, D> {
+        &mut self.eeprom
+    }
+}
+
+impl<SPI, S, V, D> Eeprom<SPI, S, V, D> {
+    /// Free the underlying EEPROM driver.
+    #[inline]
+    pub fn free(self) -> Eeprom25aa02<SPI, V, D> {
+        self.eeprom
+    }
+}
+
+impl<SPI, S, V, D> Eeprom<SPI, S, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Read from the EEPROM.
+    ///
+    /// See [`Eeprom25aa02::read`].
+    #[inline]
+    pub fn read(&mut self, address: u8, data: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        self.eeprom.read(address, data)
+    }
+
+    /// Read a single byte from the EEPROM.
+    ///
+    /// See [`Eeprom25aa02::read_byte`].
+    #[inline]
+    pub fn read_byte(&mut self, address: u8) -> Result<u8, Error<SPI::Error>> {
+        self.eeprom.read_byte(address)
+    }
+
+    /// Read the STATUS register.
+    ///
+    /// See [`Eeprom25aa02::read_status`].
+    #[inline]
+    pub fn read_status(&mut self) -> Result<StatusRegister, Error<SPI::Error>> {
+        self.eeprom.read_status()
+    }
+
+    /// Read the factory programmed node address.
+    ///
+    /// See [`Eeprom25aa02::read_node_address`].
+    #[inline]
+    pub fn read_node_address(&mut self) -> Result<V::NodeAddress, Error<SPI::Error>> {
+        self.eeprom.read_node_address()
+    }
+}
+
+impl<SPI, V, D> Eeprom<SPI, Unlocked, V, D>
+where
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Write to the EEPROM.
+    ///
+    /// See [`Eeprom25aa02::write`].
+    #[inline]
+    pub fn write(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        self.eeprom.write(address, data)
+    }
+
+    /// Write a page to the EEPROM.
+    ///
+    /// See [`Eeprom25aa02::write_page`].
+    #[inline]
+    pub fn write_page(&mut self, address: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        self.eeprom.write_page(address, data)
+    }
+
+    /// Write a single byte to the EEPROM.
+    ///
+    /// See [`Eeprom25aa02::write_byte`].
+    #[inline]
+    pub fn write_byte(&mut self, address: u8, value: u8) -> Result<(), Error<SPI::Error>> {
+        self.eeprom.write_byte(address, value)
+    }
+}
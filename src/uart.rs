@@ -0,0 +1,204 @@
+//! Transport over a framed UART protocol.
+//!
+//! This allows an EEPROM attached to a helper MCU or debug probe to be
+//! accessed from a host, the bridge firmware executes the SPI operations
+//! and returns the data read.
+//!
+//! # Protocol
+//!
+//! Each transaction is sent as a frame of commands, multi-byte integers are
+//! little endian:
+//!
+//! | Command  | Bytes                       | Bridge action                      |
+//! |----------|-----------------------------|------------------------------------|
+//! | Start    | `b'S'`                      | Assert chip select                 |
+//! | Write    | `b'W'`, `len: u16`, data    | Write `len` bytes                  |
+//! | Read     | `b'R'`, `len: u16`          | Read `len` bytes                   |
+//! | Transfer | `b'T'`, `len: u16`, data    | Write `len` bytes, reading `len`   |
+//! | Delay    | `b'D'`, `ns: u32`           | Delay for `ns` nanoseconds         |
+//! | End      | `b'E'`                      | Deassert chip select               |
+//!
+//! The bridge replies with the data from each read and transfer command in
+//! order, followed by [`ACK`] after the end command, or [`NAK`] if the
+//! transaction failed.
+//!
+//! Transfers with different read and write lengths are sent as a transfer
+//! of the common length followed by a read or write of the remainder.
+
+use crate::Transport;
+use embedded_hal::spi::{ErrorKind, Operation};
+use embedded_io::{Read, ReadExactError, Write};
+
+/// Status byte sent by the bridge when a transaction succeeds.
+pub const ACK: u8 = 0x06;
+/// Status byte sent by the bridge when a transaction fails.
+pub const NAK: u8 = 0x15;
+
+/// Start of a transaction.
+const CMD_START: u8 = b'S';
+/// Write data.
+const CMD_WRITE: u8 = b'W';
+/// Read data.
+const CMD_READ: u8 = b'R';
+/// Full-duplex transfer.
+const CMD_TRANSFER: u8 = b'T';
+/// Delay.
+const CMD_DELAY: u8 = b'D';
+/// End of a transaction.
+const CMD_END: u8 = b'E';
+
+/// UART bridge transport error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UartError<E> {
+    /// Serial port error.
+    Io(E),
+    /// The serial port reached end of file.
+    UnexpectedEof,
+    /// The bridge reported that the transaction failed.
+    Nak,
+    /// The bridge sent an unexpected status byte.
+    Protocol(u8),
+    /// An operation is longer than a frame can describe.
+    TooLong,
+}
+
+impl<E: embedded_io::Error> embedded_hal::spi::Error for UartError<E> {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+impl<E> From<ReadExactError<E>> for UartError<E> {
+    fn from(e: ReadExactError<E>) -> Self {
+        match e {
+            ReadExactError::UnexpectedEof => UartError::UnexpectedEof,
+            ReadExactError::Other(e) => UartError::Io(e),
+        }
+    }
+}
+
+/// EEPROM transport over a UART bridge.
+///
+/// # Example
+///
+/// ```
+/// # struct Serial { tx: Vec<u8>, rx: std::collections::VecDeque<u8> }
+/// # impl embedded_io::ErrorType for Serial { type Error = core::convert::Infallible; }
+/// # impl embedded_io::Read for Serial {
+/// #     fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+/// #         let n = buf.len().min(self.rx.len());
+/// #         buf.iter_mut().take(n).for_each(|b| *b = self.rx.pop_front().unwrap());
+/// #         Ok(n)
+/// #     }
+/// # }
+/// # impl embedded_io::Write for Serial {
+/// #     fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+/// #         self.tx.extend_from_slice(buf);
+/// #         Ok(buf.len())
+/// #     }
+/// #     fn flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+/// # }
+/// # let serial = Serial { tx: Vec::new(), rx: [0x12, 0x34, 0x06].into() };
+/// use eeprom25aa02e48::{uart::UartBridge, Eeprom25aa02e48};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(UartBridge::new(serial));
+/// let mut buf: [u8; 2] = [0; 2];
+/// eeprom.read(0x10, &mut buf)?;
+/// assert_eq!(buf, [0x12, 0x34]);
+/// # let serial = eeprom.free().free();
+/// # assert_eq!(serial.tx, b"SW\x02\x00\x03\x10R\x02\x00E");
+/// # Ok::<(), eeprom25aa02e48::Error<eeprom25aa02e48::uart::UartError<core::convert::Infallible>>>(())
+/// ```
+#[derive(Debug)]
+pub struct UartBridge<U> {
+    uart: U,
+}
+
+impl<U> UartBridge<U>
+where
+    U: Read + Write,
+{
+    /// Creates a new transport from a serial port connected to a bridge.
+    #[inline]
+    pub fn new(uart: U) -> Self {
+        UartBridge { uart }
+    }
+
+    /// Free the serial port.
+    #[inline]
+    pub fn free(self) -> U {
+        self.uart
+    }
+
+    fn command(&mut self, cmd: u8, len: usize) -> Result<(), UartError<U::Error>> {
+        let len: u16 = u16::try_from(len).map_err(|_| UartError::TooLong)?;
+        let [lo, hi] = len.to_le_bytes();
+        self.uart.write_all(&[cmd, lo, hi]).map_err(UartError::Io)
+    }
+}
+
+impl<U> Transport for UartBridge<U>
+where
+    U: Read + Write,
+{
+    type Error = UartError<U::Error>;
+
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+        self.uart.write_all(&[CMD_START]).map_err(UartError::Io)?;
+        for op in operations.iter() {
+            match op {
+                Operation::Write(data) => {
+                    self.command(CMD_WRITE, data.len())?;
+                    self.uart.write_all(data).map_err(UartError::Io)?;
+                }
+                Operation::Read(buf) => self.command(CMD_READ, buf.len())?,
+                Operation::Transfer(read, write) => {
+                    let common: usize = read.len().min(write.len());
+                    self.command(CMD_TRANSFER, common)?;
+                    self.uart
+                        .write_all(&write[..common])
+                        .map_err(UartError::Io)?;
+                    if write.len() > common {
+                        self.command(CMD_WRITE, write.len() - common)?;
+                        self.uart
+                            .write_all(&write[common..])
+                            .map_err(UartError::Io)?;
+                    } else if read.len() > common {
+                        self.command(CMD_READ, read.len() - common)?;
+                    }
+                }
+                Operation::TransferInPlace(buf) => {
+                    self.command(CMD_TRANSFER, buf.len())?;
+                    self.uart.write_all(buf).map_err(UartError::Io)?;
+                }
+                Operation::DelayNs(ns) => {
+                    self.uart.write_all(&[CMD_DELAY]).map_err(UartError::Io)?;
+                    self.uart
+                        .write_all(&ns.to_le_bytes())
+                        .map_err(UartError::Io)?;
+                }
+            }
+        }
+        self.uart.write_all(&[CMD_END]).map_err(UartError::Io)?;
+        self.uart.flush().map_err(UartError::Io)?;
+
+        for op in operations.iter_mut() {
+            match op {
+                Operation::Read(buf) | Operation::TransferInPlace(buf) => {
+                    self.uart.read_exact(buf)?
+                }
+                Operation::Transfer(read, _) => self.uart.read_exact(read)?,
+                Operation::Write(_) | Operation::DelayNs(_) => {}
+            }
+        }
+
+        let mut status: [u8; 1] = [0];
+        self.uart.read_exact(&mut status)?;
+        match status[0] {
+            ACK => Ok(()),
+            NAK => Err(UartError::Nak),
+            other => Err(UartError::Protocol(other)),
+        }
+    }
+}
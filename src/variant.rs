@@ -0,0 +1,59 @@
+use crate::{Eui48, EUI48_BYTES, EUI48_MEMORY_ADDRESS, EUI64_BYTES, EUI64_MEMORY_ADDRESS};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Chip variant of the 25AA02 family.
+///
+/// The variants are identical, except for the node address programmed into
+/// the upper block of the array from the factory.
+///
+/// This trait is sealed, and implemented by [`E48`] and [`E64`].
+///
+/// The variant selects which identity methods are available, reading the
+/// EUI-48 from a 25AA02E64 does not compile.
+///
+/// ```compile_fail
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[]);
+/// use eeprom25aa02e48::Eeprom25aa02e64;
+///
+/// let mut eeprom = Eeprom25aa02e64::new(spi);
+/// let eui48 = eeprom.read_eui48()?;
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub trait Variant: sealed::Sealed {
+    /// Node address type.
+    type NodeAddress: AsMut<[u8]> + Default;
+    /// Number of bytes in the node address.
+    const NODE_ADDRESS_BYTES: usize;
+    /// EEPROM memory address of the node address.
+    const NODE_ADDRESS_MEMORY_ADDRESS: u8;
+}
+
+/// 25AA02E48 variant, with an EUI-48 MAC address.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct E48;
+
+impl sealed::Sealed for E48 {}
+
+impl Variant for E48 {
+    type NodeAddress = Eui48;
+    const NODE_ADDRESS_BYTES: usize = EUI48_BYTES;
+    const NODE_ADDRESS_MEMORY_ADDRESS: u8 = EUI48_MEMORY_ADDRESS;
+}
+
+/// 25AA02E64 variant, with an EUI-64 node address.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct E64;
+
+impl sealed::Sealed for E64 {}
+
+impl Variant for E64 {
+    type NodeAddress = [u8; EUI64_BYTES];
+    const NODE_ADDRESS_BYTES: usize = EUI64_BYTES;
+    const NODE_ADDRESS_MEMORY_ADDRESS: u8 = EUI64_MEMORY_ADDRESS;
+}
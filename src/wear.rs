@@ -0,0 +1,152 @@
+use crate::{EepromRegion, Error, FieldValue, NoDelay, Transport, Variant, E48};
+use core::marker::PhantomData;
+use embedded_hal::delay::DelayNs;
+
+/// Size of the sequence number and CRC of a slot in bytes.
+const OVERHEAD: usize = 2;
+
+/// Small value rotated across the slots of a region to spread wear.
+///
+/// Each slot holds a sequence number, the value serialized with
+/// [`FieldValue`], and a CRC-8.
+/// Every write goes to the slot after the newest, so each slot is written
+/// once per rotation.
+/// If power is lost during a write the torn slot fails its CRC and the
+/// previous value is kept.
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # fn read(address: u8, data: Vec<u8>) -> Vec<hal::spi::Transaction<u8>> {
+/// #   vec![
+/// #     hal::spi::Transaction::transaction_start(),
+/// #     hal::spi::Transaction::write_vec(vec![instruction::READ, address]),
+/// #     hal::spi::Transaction::read_vec(data),
+/// #     hal::spi::Transaction::transaction_end(),
+/// #   ]
+/// # }
+/// # let mut expectations = vec![];
+/// # expectations.extend(read(0x90, vec![0xFF; 4]));
+/// # expectations.extend(read(0x94, vec![0x07, 0x10, 0x20, 0xB7]));
+/// # expectations.extend(read(0x98, vec![0xFF; 4]));
+/// # expectations.extend(read(0x9C, vec![0xFF; 4]));
+/// # expectations.extend([
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x98]),
+/// #   hal::spi::Transaction::write_vec(vec![0x08, 0x30, 0x40, 0xD0]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// # expectations.extend(read(0x90, vec![0xFF; 4]));
+/// # expectations.extend(read(0x94, vec![0x07, 0x10, 0x20, 0xB7]));
+/// # expectations.extend(read(0x98, vec![0x08, 0x30, 0x40, 0xD0]));
+/// # expectations.extend(read(0x9C, vec![0xFF; 4]));
+/// # expectations.extend(read(0x99, vec![0x30, 0x40]));
+/// # let spi = hal::spi::Mock::new(&expectations);
+/// use eeprom25aa02e48::{Eeprom25aa02e48, WearLeveled};
+///
+/// let mut eeprom = Eeprom25aa02e48::new(spi);
+/// let mut state: WearLeveled<u16, _> = WearLeveled::new(eeprom.region(0x90, 16)?)?;
+/// assert_eq!(state.slots(), 4);
+///
+/// state.write_next(&0x4030)?;
+/// assert_eq!(state.read_latest()?, 0x4030);
+/// # drop(state);
+/// # let mut spi = eeprom.free(); spi.done();
+/// # Ok::<(), eeprom25aa02e48::Error<embedded_hal::spi::ErrorKind>>(())
+/// ```
+pub struct WearLeveled<'a, T, SPI, V = E48, D = NoDelay> {
+    region: EepromRegion<'a, SPI, V, D>,
+    _value: PhantomData<T>,
+}
+
+impl<'a, T, SPI, V, D> WearLeveled<'a, T, SPI, V, D>
+where
+    T: FieldValue,
+    SPI: Transport,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Size of a slot in bytes.
+    const SLOT_SIZE: usize = T::SIZE + OVERHEAD;
+
+    /// Creates a wear-leveled value in a region.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DataTooLong`] if the region cannot hold at least two
+    /// slots.
+    pub fn new(region: EepromRegion<'a, SPI, V, D>) -> Result<Self, Error<SPI::Error>> {
+        if 2 * (T::SIZE + OVERHEAD) > region.len() {
+            Err(Error::DataTooLong)
+        } else {
+            Ok(WearLeveled {
+                region,
+                _value: PhantomData,
+            })
+        }
+    }
+
+    /// Free the underlying region.
+    #[inline]
+    pub fn free(self) -> EepromRegion<'a, SPI, V, D> {
+        self.region
+    }
+
+    /// Number of slots the value rotates across.
+    #[inline]
+    pub fn slots(&self) -> usize {
+        self.region.len() / Self::SLOT_SIZE
+    }
+
+    /// Returns the address and sequence number of the newest valid slot.
+    fn newest(&mut self) -> Result<Option<(u8, u8)>, Error<SPI::Error>> {
+        let start: u8 = self.region.start();
+        // the slots are within the region
+        let addresses = (0..self.slots()).map(|index| start + (index * Self::SLOT_SIZE) as u8);
+        self.region.eeprom.newest_slot(addresses, Self::SLOT_SIZE)
+    }
+
+    /// Read the newest value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Corrupted`] if no slot is valid.
+    pub fn read_latest(&mut self) -> Result<T, Error<SPI::Error>> {
+        let (address, _) = self.newest()?.ok_or(Error::Corrupted)?;
+        let mut bytes: T::Bytes = T::Bytes::default();
+        self.region.eeprom.read(address + 1, bytes.as_mut())?;
+        Ok(T::from_bytes(bytes))
+    }
+
+    /// Write a new value to the slot after the newest.
+    ///
+    /// Waits for each write cycle, see [write cycles](crate#write-cycles).
+    pub fn write_next(&mut self, value: &T) -> Result<(), Error<SPI::Error>> {
+        let start: u8 = self.region.start();
+        let (address, sequence): (u8, u8) = match self.newest()? {
+            Some((address, sequence)) => {
+                let index: usize = usize::from(address - start) / Self::SLOT_SIZE;
+                let next: usize = (index + 1) % self.slots();
+                // the slots are within the region
+                (
+                    start + (next * Self::SLOT_SIZE) as u8,
+                    sequence.wrapping_add(1),
+                )
+            }
+            None => (start, 0),
+        };
+        let bytes: T::Bytes = value.to_bytes();
+        self.region
+            .eeprom
+            .write_slot(address, Self::SLOT_SIZE, sequence, &[bytes.as_ref()])
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{Eeprom25aa02, Error, NoDelay, Transport, Variant, E48};
+use core::fmt;
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+
+/// Write protect error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WpError<E, P> {
+    /// EEPROM error.
+    Eeprom(Error<E>),
+    /// WP pin error.
+    Pin(P),
+}
+
+impl<E, P> From<Error<E>> for WpError<E, P> {
+    #[inline]
+    fn from(e: Error<E>) -> Self {
+        WpError::Eeprom(e)
+    }
+}
+
+impl<E: fmt::Debug, P: fmt::Debug> fmt::Display for WpError<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WpError::Eeprom(e) => e.fmt(f),
+            WpError::Pin(e) => write!(f, "WP pin error: {e:?}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug, P: fmt::Debug> core::error::Error for WpError<E, P> {}
+
+/// EEPROM driver that manages the hardware write protect (WP) pin.
+///
+/// WP is active low, while it is asserted the EEPROM ignores writes.
+/// The pin is asserted when the driver is created, and is only released for
+/// the duration of [`write`](Self::write) and
+/// [`with_unprotected`](Self::with_unprotected), or after an explicit call to
+/// [`hardware_unprotect`](Self::hardware_unprotect).
+///
+/// # Example
+///
+/// ```
+/// # use eeprom25aa02e48::instruction;
+/// # use embedded_hal_mock::eh1 as hal;
+/// # let spi = hal::spi::Mock::new(&[
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WREN]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::WRITE, 0x10]),
+/// #   hal::spi::Transaction::write_vec(vec![0x12, 0x34]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// #   hal::spi::Transaction::transaction_start(),
+/// #   hal::spi::Transaction::write_vec(vec![instruction::RDSR]),
+/// #   hal::spi::Transaction::read_vec(vec![0x00]),
+/// #   hal::spi::Transaction::transaction_end(),
+/// # ]);
+/// # let wp = hal::digital::Mock::new(&[
+/// #   hal::digital::Transaction::set(hal::digital::State::Low),
+/// #   hal::digital::Transaction::set(hal::digital::State::High),
+/// #   hal::digital::Transaction::set(hal::digital::State::Low),
+/// # ]);
+/// # let mut wp_handle = wp.clone();
+/// use eeprom25aa02e48::{Eeprom25aa02e48, WriteProtected};
+///
+/// let mut eeprom = WriteProtected::new(Eeprom25aa02e48::new(spi), wp)?;
+/// eeprom.write(0x10, &[0x12, 0x34])?;
+/// assert!(eeprom.is_protected());
+/// # let (eeprom, _) = eeprom.free(); let mut spi = eeprom.free(); spi.done(); wp_handle.done();
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct WriteProtected<SPI, WP, V = E48, D = NoDelay> {
+    eeprom: Eeprom25aa02<SPI, V, D>,
+    wp: WP,
+    protected: bool,
+}
+
+impl<SPI, WP, V, D> WriteProtected<SPI, WP, V, D>
+where
+    SPI: Transport,
+    WP: OutputPin,
+    V: Variant,
+    D: DelayNs,
+{
+    /// Creates a new driver from an EEPROM driver and the WP pin.
+    ///
+    /// The WP pin is asserted before returning.
+    pub fn new(eeprom: Eeprom25aa02<SPI, V, D>, mut wp: WP) -> Result<Self, WP::Error> {
+        wp.set_low()?;
+        Ok(WriteProtected {
+            eeprom,
+            wp,
+            protected: true,
+        })
+    }
+
+    /// Free the EEPROM driver and the WP pin.
+    ///
+    /// The WP pin is left in its current state.
+    #[inline]
+    pub fn free(self) -> (Eeprom25aa02<SPI, V, D>, WP) {
+        (self.eeprom, self.wp)
+    }
+
+    /// Returns the underlying EEPROM driver.
+    ///
+    /// Writes through the driver are ignored by the EEPROM while the WP pin
+    /// is asserted.
+    #[inline]
+    pub fn eeprom(&mut self) -> &mut Eeprom25aa02<SPI, V, D> {
+        &mut self.eeprom
+    }
+
+    /// Returns `true` if the WP pin is asserted.
+    #[inline]
+    pub fn is_protected(&self) -> bool {
+        self.protected
+    }
+
+    /// Assert the WP pin, inhibiting writes.
+    pub fn hardware_protect(&mut self) -> Result<(), WP::Error> {
+        self.wp.set_low()?;
+        self.protected = true;
+        Ok(())
+    }
+
+    /// Release the WP pin, allowing writes.
+    ///
+    /// The pin stays released until
+    /// [`hardware_protect`](Self::hardware_protect) is called.
+    pub fn hardware_unprotect(&mut self) -> Result<(), WP::Error> {
+        self.wp.set_high()?;
+        self.protected = false;
+        Ok(())
+    }
+
+    /// Release the WP pin for the duration of `f`.
+    ///
+    /// The pin is asserted again afterwards, even if `f` fails.
+    pub fn with_unprotected<T>(
+        &mut self,
+        f: impl FnOnce(&mut Eeprom25aa02<SPI, V, D>) -> Result<T, Error<SPI::Error>>,
+    ) -> Result<T, WpError<SPI::Error, WP::Error>> {
+        self.hardware_unprotect().map_err(WpError::Pin)?;
+        let result: Result<T, Error<SPI::Error>> = f(&mut self.eeprom);
+        self.hardware_protect().map_err(WpError::Pin)?;
+        Ok(result?)
+    }
+
+    /// Write data to the EEPROM, releasing the WP pin for the duration of
+    /// the write.
+    ///
+    /// See [`Eeprom25aa02::write`].
+    pub fn write(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<(), WpError<SPI::Error, WP::Error>> {
+        self.with_unprotected(|eeprom| eeprom.write(address, data))
+    }
+}